@@ -1,7 +1,9 @@
 //! Builder module provides a [`Builder`] type which helps building
 //! a [`Table`] dynamically.
 //!
-//! It also contains [`IndexBuilder`] which can help to build a table with index.
+//! It also contains [`IndexBuilder`] which can help to build a table with index,
+//! [`TreeBuilder`] which can help to build a table of hierarchical rows,
+//! and [`Pivot`], [`Unpivot`] which are used to reshape a table.
 //!
 //! # Examples
 //!
@@ -109,7 +111,7 @@
 //! )
 //! ```
 
-use std::{borrow::Cow, iter::FromIterator};
+use std::{borrow::Cow, collections::HashMap, iter::FromIterator};
 
 use papergrid::{
     records::{
@@ -118,10 +120,10 @@ use papergrid::{
         Records,
     },
     width::{CfgWidthFunction, WidthFunc},
-    AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding,
+    AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding, Position,
 };
 
-use crate::{Style, Table};
+use crate::{group_by::Aggregate, Style, Table};
 
 /// Builder creates a [`Table`] from dynamic data set.
 ///
@@ -166,6 +168,12 @@ pub struct Builder<'a> {
     different_column_sizes_used: bool,
     /// A content of cells which are created in case rows has different length.
     empty_cell_text: Option<String>,
+    /// Levels of groups above the columns row.
+    header_groups: Vec<Vec<(Cow<'a, str>, usize)>>,
+    /// Row spans of a multi-level index, positions are relative to records.
+    row_spans: Vec<(Position, usize)>,
+    /// Column spans of multi-level columns, positions are relative to records.
+    column_spans: Vec<(Position, usize)>,
 }
 
 impl<'a> Builder<'a> {
@@ -205,6 +213,62 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Sets groups of columns which are rendered above a header as spanned rows.
+    ///
+    /// Each level is a list of group names and numbers of columns they span,
+    /// levels go from the top one.
+    /// Groups are cut by a number of columns,
+    /// and an empty name can be used to leave columns without a group.
+    ///
+    /// Groups are ordinary rows of a table,
+    /// so a style which has a line only under a first row (like [`Style::psql`])
+    /// needs a [`HorizontalLine`] to be moved under a header.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_header_groups([[("", 1), ("Q1", 2), ("Q2", 2)]])
+    ///     .set_columns(["city", "Jan", "Feb", "Apr", "May"])
+    ///     .add_record(["Berlin", "1", "2", "3", "4"]);
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+-----+-----+-----+-----+\n",
+    ///         "|        | Q1        | Q2        |\n",
+    ///         "+--------+-----+-----+-----+-----+\n",
+    ///         "| city   | Jan | Feb | Apr | May |\n",
+    ///         "+--------+-----+-----+-----+-----+\n",
+    ///         "| Berlin | 1   | 2   | 3   | 4   |\n",
+    ///         "+--------+-----+-----+-----+-----+",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`HorizontalLine`]: crate::style::HorizontalLine
+    pub fn set_header_groups<L, G, T>(&mut self, levels: L) -> &mut Self
+    where
+        L: IntoIterator<Item = G>,
+        G: IntoIterator<Item = (T, usize)>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.header_groups = levels
+            .into_iter()
+            .map(|level| {
+                level
+                    .into_iter()
+                    .map(|(name, span)| (name.into(), span))
+                    .collect()
+            })
+            .collect();
+
+        self
+    }
+
     /// Sets off a [`Table`] header.
     ///
     /// If not set its a nop.
@@ -309,8 +373,29 @@ impl<'a> Builder<'a> {
         }
 
         let has_columns = self.columns.is_some();
-        let records = build_grid(self.records, self.columns, self.size);
-        build_table(records, has_columns)
+        let count_columns = self.size;
+        let (groups, spans) = create_header_groups(self.header_groups, count_columns);
+        let count_groups = groups.len();
+        let offset = count_groups + usize::from(has_columns);
+
+        let mut records = build_grid(self.records, self.columns);
+        for (row, level) in groups.into_iter().enumerate() {
+            records.insert(row, level);
+        }
+
+        let records = VecRecords::with_hint(records, count_columns);
+        let mut table = build_table(records, has_columns);
+        set_header_groups(&mut table, count_groups, spans);
+
+        let cfg = table.get_config_mut();
+        for ((row, col), span) in self.row_spans {
+            cfg.set_row_span((row + offset, col), span);
+        }
+        for ((row, col), span) in self.column_spans {
+            cfg.set_column_span((row + offset, col), span);
+        }
+
+        table
     }
 
     /// Add an index to the [`Table`].
@@ -341,6 +426,46 @@ impl<'a> Builder<'a> {
         IndexBuilder::new(self)
     }
 
+    /// Converts the builder into a [`TreeBuilder`], which draws guides of a tree in a given column.
+    ///
+    /// Already added records become roots of a tree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, Style};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name", "size"]);
+    ///
+    /// let mut tree = builder.tree(0);
+    /// let src = tree.add_root(["src", ""]);
+    /// tree.add_child(src, ["lib.rs", "12K"]);
+    /// let features = tree.add_child(src, ["features", ""]);
+    /// tree.add_child(features, ["span.rs", "4K"]);
+    /// tree.add_root(["Cargo.toml", "1K"]);
+    ///
+    /// let table = tree
+    ///     .build()
+    ///     .with(Style::modern().off_horizontal())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "┌───────────────┬──────┐\n\
+    ///      │ name          │ size │\n\
+    ///      │ src           │      │\n\
+    ///      │ ├─ lib.rs     │ 12K  │\n\
+    ///      │ └─ features   │      │\n\
+    ///      │    └─ span.rs │ 4K   │\n\
+    ///      │ Cargo.toml    │ 1K   │\n\
+    ///      └───────────────┴──────┘"
+    /// )
+    /// ```
+    pub fn tree(self, column: usize) -> TreeBuilder<'a> {
+        TreeBuilder::new(self, column)
+    }
+
     /// Clean removes empty columns and rows.
     ///
     /// # Example
@@ -377,6 +502,147 @@ impl<'a> Builder<'a> {
         CustomRecords::new(records)
     }
 
+    /// Creates a cross-tab out of records, see [`Pivot`].
+    ///
+    /// Rows of a new builder are distinct values of a row-key column,
+    /// and columns are distinct values of a column-key column,
+    /// both in order of their first appearance.
+    /// The first column keeps row keys and is named after the row-key column,
+    /// so it can be turned into an index by [`IndexBuilder::set_index`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::{Builder, Pivot};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["city", "quarter", "amount"]);
+    /// builder.add_record(["Berlin", "Q1", "10"]);
+    /// builder.add_record(["Paris", "Q2", "5"]);
+    /// builder.add_record(["Berlin", "Q2", "15"]);
+    ///
+    /// let table = builder.pivot(Pivot::new(0, 1, 2).fill("-")).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+----+----+\n\
+    ///      | city   | Q1 | Q2 |\n\
+    ///      +--------+----+----+\n\
+    ///      | Berlin | 10 | 15 |\n\
+    ///      +--------+----+----+\n\
+    ///      | Paris  | -  | 5  |\n\
+    ///      +--------+----+----+"
+    /// )
+    /// ```
+    pub fn pivot(&self, pivot: Pivot) -> Builder<'static> {
+        let mut rows = Keys::default();
+        let mut columns = Keys::default();
+        let mut values: HashMap<(usize, usize), Vec<&str>> = HashMap::new();
+        for record in &self.records {
+            let row = rows.get(get_text(record, pivot.index));
+            let col = columns.get(get_text(record, pivot.columns));
+            values
+                .entry((row, col))
+                .or_default()
+                .push(get_text(record, pivot.values));
+        }
+
+        let name = self
+            .columns
+            .as_ref()
+            .map_or("", |header| get_text(header, pivot.index));
+
+        let mut builder = Builder::default();
+        builder.set_columns(
+            std::iter::once(name)
+                .chain(columns.list.iter().copied())
+                .map(String::from),
+        );
+
+        for (row, key) in rows.list.iter().enumerate() {
+            let cells = (0..columns.list.len()).map(|col| match values.get(&(row, col)) {
+                Some(values) => pivot.aggregate.calculate(values.iter().copied()),
+                None => pivot.fill.clone(),
+            });
+
+            builder.add_record(std::iter::once(key.to_string()).chain(cells));
+        }
+
+        builder
+    }
+
+    /// Turns columns into rows (also known as melt), see [`Unpivot`].
+    ///
+    /// It's a reverse of [`Builder::pivot`].
+    /// Each not id column of each record becomes a row
+    /// which consists of id values, a column name and a value.
+    /// Rows go column by column.
+    ///
+    /// A column name is taken from a header (see [`Builder::set_columns`]),
+    /// or it's a column index if there's no header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::{Builder, Unpivot};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["city", "Q1", "Q2"]);
+    /// builder.add_record(["Berlin", "10", "15"]);
+    /// builder.add_record(["Paris", "", "5"]);
+    ///
+    /// let table = builder
+    ///     .unpivot(Unpivot::new([0]).names("quarter", "amount"))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+---------+--------+\n\
+    ///      | city   | quarter | amount |\n\
+    ///      +--------+---------+--------+\n\
+    ///      | Berlin | Q1      | 10     |\n\
+    ///      +--------+---------+--------+\n\
+    ///      | Paris  | Q1      |        |\n\
+    ///      +--------+---------+--------+\n\
+    ///      | Berlin | Q2      | 15     |\n\
+    ///      +--------+---------+--------+\n\
+    ///      | Paris  | Q2      | 5      |\n\
+    ///      +--------+---------+--------+"
+    /// )
+    /// ```
+    pub fn unpivot(&self, unpivot: Unpivot) -> Builder<'static> {
+        let count_columns = self.get_size();
+        let header = self.columns.as_deref().unwrap_or_default();
+        let get_name = |col: usize| match self.columns {
+            Some(_) => get_text(header, col).to_owned(),
+            None => col.to_string(),
+        };
+
+        let mut builder = Builder::default();
+        builder.set_columns(
+            unpivot
+                .ids
+                .iter()
+                .map(|&col| get_name(col))
+                .chain([unpivot.names.0.clone(), unpivot.names.1.clone()]),
+        );
+
+        let columns = (0..count_columns).filter(|col| !unpivot.ids.contains(col));
+        for col in columns {
+            let name = get_name(col);
+            for record in &self.records {
+                let ids = unpivot.ids.iter().map(|&id| get_text(record, id));
+                let row = ids
+                    .chain([name.as_str(), get_text(record, col)])
+                    .map(String::from);
+
+                builder.add_record(row);
+            }
+        }
+
+        builder
+    }
+
     fn clean_columns(&mut self) {
         let mut i = 0;
         for col in 0..self.size {
@@ -401,6 +667,8 @@ impl<'a> Builder<'a> {
                     }
                 }
 
+                remove_header_groups_column(&mut self.header_groups, col);
+
                 i += 1;
             }
         }
@@ -446,7 +714,7 @@ impl<'a> Builder<'a> {
         }
     }
 
-    fn get_size(&mut self) -> usize {
+    fn get_size(&self) -> usize {
         let mut max = self.columns.as_ref().map_or(0, Vec::len);
 
         let max_records = self.records.iter().map(Vec::len).max().unwrap_or_default();
@@ -546,11 +814,13 @@ impl<'a> From<Vec<Vec<CellInfo<'a>>>> for Builder<'a> {
 /// ```
 #[derive(Debug, Clone)]
 pub struct IndexBuilder<'a> {
-    /// Index is an index data.
+    /// Index is an index data, a column per level.
     /// It's always set.
-    index: Vec<CellInfo<'a>>,
-    /// Name of an index
-    name: Option<CellInfo<'a>>,
+    index: Vec<Vec<CellInfo<'a>>>,
+    /// Names of index levels.
+    names: Option<Vec<CellInfo<'a>>>,
+    /// Columns of a table, a row per level.
+    columns: Vec<Vec<CellInfo<'a>>>,
     /// A flag which checks if we need to actually use index.
     ///
     /// It might happen when it's only necessary to [Self::transpose] table.
@@ -589,14 +859,15 @@ impl<'a> IndexBuilder<'a> {
     /// ```
     fn new(mut b: Builder<'a>) -> Self {
         let index = build_range_index(b.records.len());
-
-        if b.columns.is_none() {
-            b.columns = Some(build_range_index(b.size));
-        }
+        let columns = b
+            .columns
+            .take()
+            .unwrap_or_else(|| build_range_index(b.size));
 
         Self {
-            index,
-            name: None,
+            index: vec![index],
+            names: None,
+            columns: vec![columns],
             print_index: true,
             transposed: false,
             b,
@@ -639,14 +910,59 @@ impl<'a> IndexBuilder<'a> {
     /// Set an index name.
     ///
     /// When [`None`] the name won't be used.
+    ///
+    /// In case of a multi-level index it's a name of the first level,
+    /// see [`Self::set_names`].
     pub fn set_name(&mut self, name: Option<String>) -> &mut Self {
-        self.name = name.map(|s| {
+        self.names = name.map(|s| {
             let ctrl = CfgWidthFunction::new(4);
-            CellInfo::new(s, ctrl)
+            vec![CellInfo::new(s, ctrl)]
         });
         self
     }
 
+    /// Set names of index levels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["region", "city", "amount"]);
+    /// builder.add_record(["EU", "Berlin", "10"]);
+    ///
+    /// let mut builder = builder.index();
+    /// builder.set_multi_index([0, 1]).set_names(["Region", "City"]);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+--------+--------+\n\
+    ///      |        |        | amount |\n\
+    ///      +--------+--------+--------+\n\
+    ///      | Region | City   |        |\n\
+    ///      +--------+--------+--------+\n\
+    ///      | EU     | Berlin | 10     |\n\
+    ///      +--------+--------+--------+"
+    /// )
+    /// ```
+    pub fn set_names<I, T>(&mut self, names: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let ctrl = CfgWidthFunction::new(4);
+        let names = names
+            .into_iter()
+            .map(|name| CellInfo::new(name.into(), &ctrl))
+            .collect();
+
+        self.names = Some(names);
+        self
+    }
+
     /// Sets a index to the chosen column.
     ///
     /// Also sets a name of the index to the column name.
@@ -677,26 +993,87 @@ impl<'a> IndexBuilder<'a> {
     /// )
     /// ```
     pub fn set_index(&mut self, column: usize) -> &mut Self {
-        if self.b.columns.is_none() {
-            return self;
-        }
+        self.set_multi_index([column])
+    }
+
+    /// Sets a multi-level index to the chosen columns, the first one is the outer level.
+    ///
+    /// Repeated values of outer levels are shown once and spanned,
+    /// like in a pandas `MultiIndex`.
+    /// Also sets names of the index levels to the columns names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["region", "city", "amount"]);
+    /// builder.add_record(["EU", "Berlin", "10"]);
+    /// builder.add_record(["EU", "Paris", "5"]);
+    /// builder.add_record(["US", "Boston", "20"]);
+    ///
+    /// let mut builder = builder.index();
+    /// builder.set_multi_index([0, 1]).set_name(None);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----+--------+--------+\n\
+    ///      |    |        | amount |\n\
+    ///      +----+--------+--------+\n\
+    ///      | EU | Berlin | 10     |\n\
+    ///      +    +--------+--------+\n\
+    ///      |    | Paris  | 5      |\n\
+    ///      +----+--------+--------+\n\
+    ///      | US | Boston | 20     |\n\
+    ///      +----+--------+--------+"
+    /// )
+    /// ```
+    pub fn set_multi_index<I>(&mut self, columns: I) -> &mut Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let columns = columns.into_iter().collect::<Vec<_>>();
+
+        let mut sorted = columns.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
 
-        if column >= self.b.size {
+        let is_valid = !sorted.is_empty()
+            && sorted.len() == columns.len()
+            && sorted.iter().all(|&col| col < self.b.size);
+        if !is_valid {
             return self;
         }
 
-        let name = self
-            .b
-            .columns
-            .as_mut()
-            .map(|v| remove_or_default(v, column))
-            .unwrap_or_default();
+        // columns are removed from the last one so indexes stay valid
+        let mut levels = Vec::with_capacity(sorted.len());
+        for &col in sorted.iter().rev() {
+            let mut name = CellInfo::default();
+            for level in &mut self.columns {
+                name = remove_or_default(level, col);
+            }
 
-        self.name = Some(name);
+            let index = get_column(&mut self.b.records, col);
+            remove_header_groups_column(&mut self.b.header_groups, col);
+            self.b.size -= 1;
 
-        self.index = get_column(&mut self.b.records, column);
+            levels.push((col, name, index));
+        }
 
-        self.b.size -= 1;
+        let mut names = Vec::with_capacity(columns.len());
+        let mut index = Vec::with_capacity(columns.len());
+        for col in columns {
+            let pos = levels.iter().position(|(c, _, _)| *c == col).unwrap();
+            let (_, name, level) = levels.swap_remove(pos);
+            names.push(name);
+            index.push(level);
+        }
+
+        self.names = Some(names);
+        self.index = index;
 
         self
     }
@@ -733,10 +1110,8 @@ impl<'a> IndexBuilder<'a> {
     /// )
     /// ```
     pub fn transpose(&mut self) -> &mut Self {
-        let columns = self.b.columns.take().unwrap_or_default();
-
-        let index = std::mem::replace(&mut self.index, columns);
-        self.b.columns = Some(index);
+        let index = std::mem::take(&mut self.index);
+        self.index = std::mem::replace(&mut self.columns, index);
 
         let new_count_columns = self.b.records.len();
         make_rows_columns(&mut self.b.records, self.b.size);
@@ -744,6 +1119,9 @@ impl<'a> IndexBuilder<'a> {
 
         self.transposed = !self.transposed;
 
+        // groups of columns make no sense after the columns became rows
+        self.b.header_groups.clear();
+
         self
     }
 
@@ -765,6 +1143,143 @@ impl<'a> From<IndexBuilder<'a>> for Builder<'a> {
     }
 }
 
+/// [`TreeBuilder`] helps to build a table of hierarchical rows,
+/// like a dependency tree or a directory listing.
+///
+/// Rows are ordered depth first, and guides of a tree are drawn in a chosen column.
+/// Guides are a part of a content, so they work with any [`Style`].
+///
+/// See [`Builder::tree`].
+#[derive(Debug, Clone)]
+pub struct TreeBuilder<'a> {
+    /// Parents of records, an index of a record is an id of a node.
+    parents: Vec<Option<usize>>,
+    /// A column where guides are drawn.
+    column: usize,
+    /// A depth of nodes which children are hidden.
+    collapse: Option<usize>,
+    guides: TreeGuides,
+    /// Original builder instance.
+    b: Builder<'a>,
+}
+
+impl<'a> TreeBuilder<'a> {
+    fn new(b: Builder<'a>, column: usize) -> Self {
+        Self {
+            parents: vec![None; b.records.len()],
+            column,
+            collapse: None,
+            guides: TreeGuides::default(),
+            b,
+        }
+    }
+
+    /// Adds a root row and returns its id.
+    pub fn add_root<R, T>(&mut self, row: R) -> usize
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.add_node(None, row)
+    }
+
+    /// Adds a child row of a given node and returns its id.
+    ///
+    /// If there's no such node the row is added as a root.
+    pub fn add_child<R, T>(&mut self, parent: usize, row: R) -> usize
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        let parent = Some(parent).filter(|&parent| parent < self.parents.len());
+        self.add_node(parent, row)
+    }
+
+    /// Hides children of nodes at a given depth.
+    ///
+    /// Roots have a depth 0, so `collapse(0)` leaves only roots.
+    pub fn collapse(&mut self, depth: usize) -> &mut Self {
+        self.collapse = Some(depth);
+        self
+    }
+
+    /// Sets guides which are used to draw a tree.
+    pub fn set_guides(&mut self, guides: TreeGuides) -> &mut Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Builds a table.
+    pub fn build(self) -> Table<VecRecords<CellInfo<'a>>> {
+        Builder::from(self).build()
+    }
+
+    fn add_node<R, T>(&mut self, parent: Option<usize>, row: R) -> usize
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.b.add_record(row);
+        self.parents.push(parent);
+        self.parents.len() - 1
+    }
+}
+
+impl<'a> From<TreeBuilder<'a>> for Builder<'a> {
+    fn from(tree: TreeBuilder<'a>) -> Self {
+        build_tree(tree)
+    }
+}
+
+/// [`TreeGuides`] is a set of prefixes used by [`TreeBuilder`] to draw a tree.
+///
+/// By default [`TreeGuides::unicode`] is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeGuides {
+    branch: String,
+    last: String,
+    vertical: String,
+    space: String,
+}
+
+impl TreeGuides {
+    /// Creates guides.
+    ///
+    /// - `branch` is a prefix of a node which is followed by its siblings;
+    /// - `last` is a prefix of a last node among its siblings;
+    /// - `vertical` is a prefix which continues a branch below a node;
+    /// - `space` is a prefix below a last node.
+    ///
+    /// All of them are expected to be the same width.
+    pub fn new<S>(branch: S, last: S, vertical: S, space: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            branch: branch.into(),
+            last: last.into(),
+            vertical: vertical.into(),
+            space: space.into(),
+        }
+    }
+
+    /// Box-drawing guides (`├─ `, `└─ `, `│  `).
+    pub fn unicode() -> Self {
+        Self::new("├─ ", "└─ ", "│  ", "   ")
+    }
+
+    /// ASCII guides (`|- `, `` `- ``, `|  `).
+    pub fn ascii() -> Self {
+        Self::new("|- ", "`- ", "|  ", "   ")
+    }
+}
+
+impl Default for TreeGuides {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
 /// A builder which wraps [`Records`] and builds [`Table`] out of it.
 ///
 /// [`Records`]: papergrid::records::Records
@@ -800,6 +1315,100 @@ where
     }
 }
 
+/// A configuration of [`Builder::pivot`].
+///
+/// By default duplicates are aggregated by [`Aggregate::First`],
+/// and cells without values are left empty.
+#[derive(Debug, Clone)]
+pub struct Pivot {
+    index: usize,
+    columns: usize,
+    values: usize,
+    aggregate: Aggregate,
+    fill: String,
+}
+
+impl Pivot {
+    /// Creates a [`Pivot`] by a row-key column, a column-key column and a value column.
+    pub fn new(index: usize, columns: usize, values: usize) -> Self {
+        Self {
+            index,
+            columns,
+            values,
+            aggregate: Aggregate::First,
+            fill: String::new(),
+        }
+    }
+
+    /// Sets an aggregation of values which have the same row and column keys.
+    pub fn aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+
+    /// Sets a text of cells which have no values.
+    pub fn fill<S>(mut self, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.fill = text.into();
+        self
+    }
+}
+
+/// A configuration of [`Builder::unpivot`].
+///
+/// By default new columns are named `variable` and `value`.
+#[derive(Debug, Clone)]
+pub struct Unpivot {
+    ids: Vec<usize>,
+    names: (String, String),
+}
+
+impl Unpivot {
+    /// Creates an [`Unpivot`] which keeps given id columns in each row.
+    pub fn new<I>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            ids: ids.into_iter().collect(),
+            names: (String::from("variable"), String::from("value")),
+        }
+    }
+
+    /// Sets names of a column with former column names and a column with values.
+    pub fn names<V, T>(mut self, variable: V, value: T) -> Self
+    where
+        V: Into<String>,
+        T: Into<String>,
+    {
+        self.names = (variable.into(), value.into());
+        self
+    }
+}
+
+/// A list of distinct keys in order of their appearance.
+#[derive(Debug, Default)]
+struct Keys<'a> {
+    list: Vec<&'a str>,
+    index: HashMap<&'a str, usize>,
+}
+
+impl<'a> Keys<'a> {
+    fn get(&mut self, key: &'a str) -> usize {
+        let list = &mut self.list;
+        *self.index.entry(key).or_insert_with(|| {
+            list.push(key);
+            list.len() - 1
+        })
+    }
+}
+
+fn get_text<'a>(row: &'a [CellInfo<'_>], col: usize) -> &'a str {
+    row.get(col).map_or("", AsRef::as_ref)
+}
+
 fn make_rows_columns(v: &mut Vec<Vec<CellInfo<'_>>>, count_columns: usize) {
     let mut columns = Vec::with_capacity(count_columns);
     for _ in 0..count_columns {
@@ -815,40 +1424,111 @@ fn make_rows_columns(v: &mut Vec<Vec<CellInfo<'_>>>, count_columns: usize) {
 }
 
 fn build_index(mut b: IndexBuilder<'_>) -> Builder<'_> {
-    if b.index.is_empty() {
+    if b.index.iter().all(Vec::is_empty) {
+        if b.columns.len() == 1 {
+            b.b.columns = b.columns.pop();
+        } else {
+            b.b.records.splice(0..0, b.columns);
+        }
+
         return b.b;
     }
 
-    let records = &mut b.b.records;
+    let count_levels = if b.print_index { b.index.len() } else { 0 };
+    let count_header_levels = b.columns.len();
 
-    // it's guaranteed to be set
-    let columns = b.b.columns.take().unwrap();
+    let mut column_spans = sparsify_levels(&mut b.columns);
+    let mut row_spans = Vec::new();
+    if b.print_index {
+        let spans = sparsify_levels(&mut b.index);
+        row_spans.extend(
+            spans
+                .into_iter()
+                .map(|((col, row), span)| ((row, col), span)),
+        );
+    }
 
-    records.insert(0, columns);
+    let records = &mut b.b.records;
+    records.splice(0..0, b.columns);
 
-    // add index column
+    // add index columns
     if b.print_index {
-        b.b.size += 1;
-        b.index.insert(0, CellInfo::default());
-        insert_column(records, b.index, 0);
+        for level in &mut b.b.header_groups {
+            level.insert(0, (Cow::Borrowed(""), count_levels));
+        }
+
+        b.b.size += count_levels;
+        for (col, level) in b.index.into_iter().enumerate() {
+            let header = (0..count_header_levels).map(|_| CellInfo::default());
+            let mut values = header.chain(level);
+            for row in records.iter_mut() {
+                row.insert(col, values.next().unwrap_or_default());
+            }
+        }
     }
 
-    if let Some(name) = b.name {
+    let mut count_names_rows = 0;
+    if let Some(names) = b.names {
         if b.transposed && b.print_index {
-            records[0][0] = name;
+            for (row, name) in names.into_iter().enumerate().take(count_header_levels) {
+                records[row][count_levels - 1] = name;
+            }
         } else {
-            records.insert(1, vec![name]);
+            records.insert(count_header_levels, names);
+            count_names_rows = 1;
         }
     }
 
+    for ((_, col), _) in &mut column_spans {
+        *col += count_levels;
+    }
+    for ((row, _), _) in &mut row_spans {
+        *row += count_header_levels + count_names_rows;
+    }
+
+    b.b.column_spans = column_spans;
+    b.b.row_spans = row_spans;
+
     b.b
 }
 
-fn insert_column<T: Default>(v: &mut [Vec<T>], mut column: Vec<T>, col: usize) {
-    for row in v.iter_mut() {
-        let value = remove_or_default(&mut column, col);
-        row.insert(col, value);
+/// Clears repeated values of outer levels and returns their spans.
+///
+/// A level is a list of values, positions of spans are (level, value index).
+/// A span of a level never crosses a span of its outer level,
+/// and the last level is never spanned.
+fn sparsify_levels(levels: &mut [Vec<CellInfo<'_>>]) -> Vec<(Position, usize)> {
+    let count_values = levels.iter().map(Vec::len).min().unwrap_or(0);
+    let count_levels = levels.len().saturating_sub(1);
+
+    let mut spans = Vec::new();
+    for level in 0..count_levels {
+        let mut start = 0;
+        while start < count_values {
+            let mut end = start + 1;
+            while end < count_values
+                && levels[..=level]
+                    .iter()
+                    .all(|values| values[end].as_ref() == values[start].as_ref())
+            {
+                end += 1;
+            }
+
+            if end - start > 1 {
+                spans.push(((level, start), end - start));
+            }
+
+            start = end;
+        }
     }
+
+    for &((level, start), span) in &spans {
+        for value in &mut levels[level][start + 1..start + span] {
+            *value = CellInfo::default();
+        }
+    }
+
+    spans
 }
 
 fn get_column<'a>(v: &mut [Vec<CellInfo<'a>>], col: usize) -> Vec<CellInfo<'a>> {
@@ -869,6 +1549,99 @@ fn remove_or_default<T: Default>(v: &mut Vec<T>, i: usize) -> T {
     }
 }
 
+/// Orders records of a tree depth first, adding guides to them.
+fn build_tree(tree: TreeBuilder<'_>) -> Builder<'_> {
+    let TreeBuilder {
+        parents,
+        column,
+        collapse,
+        guides,
+        mut b,
+    } = tree;
+
+    let mut roots = Vec::new();
+    let mut children = vec![Vec::new(); parents.len()];
+    for (node, parent) in parents.into_iter().enumerate() {
+        match parent {
+            Some(parent) => children[parent].push(node),
+            None => roots.push(node),
+        }
+    }
+
+    let mut order = Vec::with_capacity(children.len());
+    let mut stack = roots
+        .into_iter()
+        .rev()
+        .map(|node| (node, 0, None, String::new()))
+        .collect::<Vec<_>>();
+
+    // (node, depth, is_last sibling for not root nodes, a prefix of ancestors)
+    while let Some((node, depth, is_last, prefix)) = stack.pop() {
+        let (first_prefix, next_prefix) = match is_last {
+            Some(true) => (prefix.clone() + &guides.last, prefix + &guides.space),
+            Some(false) => (prefix.clone() + &guides.branch, prefix + &guides.vertical),
+            None => (prefix.clone(), prefix),
+        };
+
+        let is_collapsed = matches!(collapse, Some(collapse) if depth >= collapse);
+        if !is_collapsed {
+            let count = children[node].len();
+            for (i, &child) in children[node].iter().enumerate().rev() {
+                stack.push((child, depth + 1, Some(i + 1 == count), next_prefix.clone()));
+            }
+        }
+
+        order.push((node, first_prefix, next_prefix));
+    }
+
+    let ctrl = CfgWidthFunction::new(4);
+    let mut records = std::mem::take(&mut b.records)
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+
+    for (node, first_prefix, next_prefix) in order {
+        let mut row = records[node].take().expect("a node is visited once");
+        if column < b.size {
+            if row.len() <= column {
+                let text = b.empty_cell_text.clone().unwrap_or_default();
+                row.resize(column + 1, CellInfo::new(text, &ctrl));
+            }
+
+            let text = add_tree_prefix(row[column].as_ref(), &first_prefix, &next_prefix);
+            CellMut::set(&mut row[column], text, &ctrl);
+        }
+
+        b.records.push(row);
+    }
+
+    // spans of rows make no sense after the rows are reordered
+    b.row_spans.clear();
+    b.column_spans.clear();
+
+    b
+}
+
+fn add_tree_prefix(text: &str, first_prefix: &str, next_prefix: &str) -> String {
+    let mut buf = String::new();
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            buf.push('\n');
+            buf.push_str(next_prefix);
+        } else {
+            buf.push_str(first_prefix);
+        }
+
+        buf.push_str(line);
+    }
+
+    if buf.is_empty() {
+        buf.push_str(first_prefix);
+    }
+
+    buf
+}
+
 fn build_range_index(n: usize) -> Vec<CellInfo<'static>> {
     let ctrl = CfgWidthFunction::new(4);
     (0..n)
@@ -919,13 +1692,78 @@ where
 fn build_grid<'a>(
     mut records: Vec<Vec<CellInfo<'a>>>,
     columns: Option<Vec<CellInfo<'a>>>,
-    count_columns: usize,
-) -> VecRecords<CellInfo<'a>> {
+) -> Vec<Vec<CellInfo<'a>>> {
     if let Some(columns) = columns {
         records.insert(0, columns);
     }
 
-    VecRecords::with_hint(records, count_columns)
+    records
+}
+
+/// Creates rows of header groups and a list of their column spans.
+pub(crate) fn create_header_groups<'a>(
+    levels: Vec<Vec<(Cow<'a, str>, usize)>>,
+    count_columns: usize,
+) -> (Vec<Vec<CellInfo<'a>>>, Vec<(Position, usize)>) {
+    if levels.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let ctrl = CfgWidthFunction::new(4);
+
+    let mut rows = Vec::with_capacity(levels.len());
+    let mut spans = Vec::new();
+    for (row, level) in levels.into_iter().enumerate() {
+        let mut list = vec![CellInfo::default(); count_columns];
+        let mut col = 0;
+        for (name, span) in level {
+            let span = std::cmp::min(span, count_columns - col);
+            if span == 0 {
+                continue;
+            }
+
+            list[col] = CellInfo::new(name, &ctrl);
+            if span > 1 {
+                spans.push(((row, col), span));
+            }
+
+            col += span;
+        }
+
+        rows.push(list);
+    }
+
+    (rows, spans)
+}
+
+/// Sets spans of header groups and marks their rows.
+pub(crate) fn set_header_groups<R>(
+    table: &mut Table<R>,
+    count_rows: usize,
+    spans: Vec<(Position, usize)>,
+) {
+    table.set_header_groups_count(count_rows);
+    for (pos, span) in spans {
+        table.get_config_mut().set_column_span(pos, span);
+    }
+}
+
+/// Removes a column from header groups, shrinking a group which contains it.
+fn remove_header_groups_column(levels: &mut [Vec<(Cow<'_, str>, usize)>], col: usize) {
+    for level in levels {
+        let mut end = 0;
+        let group = level.iter().position(|&(_, span)| {
+            end += span;
+            col < end
+        });
+
+        if let Some(i) = group {
+            level[i].1 -= 1;
+            if level[i].1 == 0 {
+                level.remove(i);
+            }
+        }
+    }
 }
 
 fn configure_grid(cfg: &mut GridConfig) {
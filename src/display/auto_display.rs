@@ -0,0 +1,140 @@
+//! This module contains an [`AutoDisplay`] structure which chooses between
+//! a regular [`Table`] and an [`ExpandedDisplay`] depending on a width.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{cell_info::CellInfo, vec_records::VecRecords};
+
+use crate::{display::ExpandedDisplay, Table, TableOption, Tabled};
+
+/// `AutoDisplay` is an imitation of psql `\x auto` mode.
+///
+/// It renders a regular [`Table`] if it's not wider than a given width,
+/// and an [`ExpandedDisplay`] otherwise.
+/// Both layouts are built from the same data,
+/// and options set by [`AutoDisplay::with`] are applied to both of them.
+///
+/// The width of a table is checked on rendering by [`Table::total_width`],
+/// so all options applied before are taken into account.
+///
+/// ```
+/// use tabled::{display::AutoDisplay, Style};
+///
+/// let data = vec![("Hello", "World"), ("Hi", "Bob")];
+///
+/// let mut display = AutoDisplay::new(&data, 20);
+/// display.with(Style::modern());
+///
+/// assert!(!display.is_expanded());
+/// assert_eq!(
+///     display.to_string(),
+///     concat!(
+///         "┌───────┬───────┐\n",
+///         "│ &str  │ &str  │\n",
+///         "├───────┼───────┤\n",
+///         "│ Hello │ World │\n",
+///         "├───────┼───────┤\n",
+///         "│ Hi    │ Bob   │\n",
+///         "└───────┴───────┘",
+///     )
+/// );
+///
+/// let mut display = AutoDisplay::new(&data, 10);
+/// display.with(Style::modern());
+///
+/// assert!(display.is_expanded());
+/// assert_eq!(
+///     display.to_string(),
+///     concat!(
+///         "-[ RECORD 0 ]-\n",
+///         "│&str │ Hello│\n",
+///         "├─────┼──────┤\n",
+///         "│&str │ World│\n",
+///         "-[ RECORD 1 ]-\n",
+///         "│&str │ Hi   │\n",
+///         "├─────┼──────┤\n",
+///         "│&str │ Bob  │\n",
+///         "└─────┴──────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::total_width`]: crate::Table::total_width
+#[derive(Debug, Clone)]
+pub struct AutoDisplay {
+    table: Table,
+    expanded: ExpandedDisplay,
+    width: usize,
+}
+
+impl AutoDisplay {
+    /// Creates a new instance of `AutoDisplay` with a maximum width of a regular table.
+    pub fn new<T>(iter: impl IntoIterator<Item = T>, width: usize) -> Self
+    where
+        T: Tabled,
+    {
+        let records = iter.into_iter().collect::<Vec<_>>();
+        let expanded = ExpandedDisplay::raw(&records);
+        let table = Table::new(records);
+
+        Self {
+            table,
+            expanded,
+            width,
+        }
+    }
+
+    /// With is a generic function which applies options to both layouts.
+    ///
+    /// Be aware that the layouts have a different shape,
+    /// so an option which targets some rows or columns should be applied
+    /// by [`AutoDisplay::with_table`] or [`AutoDisplay::with_expanded`].
+    pub fn with<O>(&mut self, mut option: O) -> &mut Self
+    where
+        O: TableOption<VecRecords<CellInfo<'static>>>,
+    {
+        self.table.with(&mut option);
+        self.expanded.with(&mut option);
+        self
+    }
+
+    /// Applies an option to a regular table only.
+    pub fn with_table<O>(&mut self, option: O) -> &mut Self
+    where
+        O: TableOption<VecRecords<CellInfo<'static>>>,
+    {
+        self.table.with(option);
+        self
+    }
+
+    /// Applies an option to an expanded display only.
+    pub fn with_expanded<O>(&mut self, option: O) -> &mut Self
+    where
+        O: TableOption<VecRecords<CellInfo<'static>>>,
+    {
+        self.expanded.with(option);
+        self
+    }
+
+    /// Returns a mutable reference to an expanded display,
+    /// to set its header for example.
+    pub fn get_expanded_mut(&mut self) -> &mut ExpandedDisplay {
+        &mut self.expanded
+    }
+
+    /// Verifies whether an expanded layout will be used.
+    pub fn is_expanded(&self) -> bool {
+        self.table.total_width() > self.width
+    }
+}
+
+impl std::fmt::Display for AutoDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_expanded() {
+            self.expanded.fmt(f)
+        } else {
+            self.table.fmt(f)
+        }
+    }
+}
@@ -48,17 +48,27 @@
 //! assert_eq!(table, expected);
 //! ```
 
-use papergrid::util::{cut_str_basic, string_width};
+use papergrid::{
+    records::{cell_info::CellInfo, vec_records::VecRecords, Records, RecordsMut, Resizable},
+    util::{cut_str_basic, string_width},
+    width::CfgWidthFunction,
+    Borders, Entity, HorizontalLine, Indent, Offset, Padding,
+};
 
-use crate::Tabled;
+use crate::{builder::Builder, Table, TableOption, Tabled};
 
 /// `ExpandedDisplay` display data in a 'expanded display mode' from postgresql.
 /// It may be useful for a large data sets with a lot of fields.
 ///
 /// See 'Examples' in <https://www.postgresql.org/docs/current/app-psql.html.>.
 ///
-/// It escapes strings to resolve a multi-line ones.
+/// Each record is rendered as a list of `field | value` rows of a [`Table`],
+/// with a record header placed on a horizontal line before it,
+/// so any [`TableOption`] like [`Style`] or [`Width::wrap`] can be used via [`ExpandedDisplay::with`].
+///
+/// [`ExpandedDisplay::new`] escapes strings to resolve a multi-line ones.
 /// Because of that ANSI sequences will be not be rendered too so colores will not be showed.
+/// Use [`ExpandedDisplay::raw`] to keep them as they are.
 ///
 /// ```
 /// use tabled::{display::ExpandedDisplay};
@@ -76,10 +86,45 @@ use crate::Tabled;
 ///     )
 /// );
 /// ```
+///
+/// ```
+/// use tabled::{display::ExpandedDisplay, object::Segment, Modify, Padding, Style};
+///
+/// let data = vec![["Hello\nWorld", "1"], ["Hi", "2"], ["Bye", "3"]];
+///
+/// let table = ExpandedDisplay::raw(&data)
+///     .select([0, 2])
+///     .header(|i| format!("= {} ", i + 1))
+///     .with(Style::modern())
+///     .with(Modify::new(Segment::all()).with(Padding::new(1, 1, 0, 0)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "= 1 ┬───────┐\n",
+///         "│ 0 │ Hello │\n",
+///         "│   │ World │\n",
+///         "├───┼───────┤\n",
+///         "│ 1 │ 1     │\n",
+///         "= 3 ┼───────┤\n",
+///         "│ 0 │ Bye   │\n",
+///         "├───┼───────┤\n",
+///         "│ 1 │ 3     │\n",
+///         "└───┴───────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Style`]: crate::Style
+/// [`Width::wrap`]: crate::Width::wrap
 #[derive(Debug, Clone)]
 pub struct ExpandedDisplay {
-    fields: Vec<String>,
-    records: Vec<Vec<String>>,
+    table: Table,
+    count_fields: usize,
+    records: Vec<usize>,
+    headers: Vec<String>,
+    keep_trailing_spaces: bool,
 }
 
 impl ExpandedDisplay {
@@ -88,24 +133,118 @@ impl ExpandedDisplay {
     where
         T: Tabled,
     {
-        let data = iter
-            .into_iter()
-            .map(|i| {
-                i.fields()
-                    .into_iter()
-                    .map(|s| s.escape_debug().to_string())
-                    .collect()
-            })
-            .collect();
-        let header = T::headers()
+        Self::create(iter, |text| text.escape_debug().to_string())
+    }
+
+    /// Creates a new instance of `ExpandedDisplay` which doesn't escape its content,
+    /// so multiline values are rendered on several lines.
+    pub fn raw<T>(iter: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Tabled,
+    {
+        Self::create(iter, |text| text.to_owned())
+    }
+
+    fn create<T, F>(iter: impl IntoIterator<Item = T>, f: F) -> Self
+    where
+        T: Tabled,
+        F: Fn(&str) -> String,
+    {
+        let fields = T::headers().iter().map(|s| f(s)).collect::<Vec<_>>();
+        let records = iter
             .into_iter()
-            .map(|s| s.escape_debug().to_string())
-            .collect();
+            .map(|record| record.fields().iter().map(|s| f(s)).collect());
+
+        Self::from_parts(fields, records)
+    }
+
+    fn from_parts<I>(fields: Vec<String>, records: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<String>>,
+    {
+        let mut builder = Builder::default();
+        let mut count_records = 0;
+        for record in records {
+            for (field, value) in fields.iter().zip(record) {
+                builder.add_record([field.clone(), value]);
+            }
+
+            count_records += 1;
+        }
+
+        let mut table = builder.build();
+        configure_table(&mut table);
+
+        let mut display = Self {
+            table,
+            count_fields: fields.len(),
+            records: (0..count_records).collect(),
+            headers: Vec::new(),
+            keep_trailing_spaces: false,
+        };
+
+        display.header(|i| format!("-[ RECORD {i} ]-"));
+
+        display
+    }
+
+    /// With is a generic function which applies options to the underlying [`Table`].
+    ///
+    /// Each row of the table is a field of a record and there are 2 columns,
+    /// a field name and a value.
+    pub fn with<O>(&mut self, option: O) -> &mut Self
+    where
+        O: TableOption<VecRecords<CellInfo<'static>>>,
+    {
+        self.table.with(option);
+        self
+    }
+
+    /// Sets a header of a record, which is printed on a horizontal line before it.
+    ///
+    /// The function gets an original index of a record.
+    /// By default it's `-[ RECORD {index} ]-`.
+    pub fn header<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(usize) -> String,
+    {
+        self.headers = self.records.iter().map(|&i| f(i)).collect();
+        self
+    }
+
+    /// Keeps trailing spaces of lines, so each line has a width of the table.
+    ///
+    /// By default trailing spaces are trimmed.
+    pub fn keep_trailing_spaces(&mut self, keep: bool) -> &mut Self {
+        self.keep_trailing_spaces = keep;
+        self
+    }
+
+    /// Keeps only records with given original indexes and removes the rest.
+    pub fn select<I>(&mut self, records: I) -> &mut Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let list = records.into_iter().collect::<Vec<_>>();
+
+        for (pos, index) in self.records.clone().into_iter().enumerate().rev() {
+            if list.contains(&index) {
+                continue;
+            }
 
-        Self {
-            records: data,
-            fields: header,
+            let start = pos * self.count_fields;
+            for row in (start..start + self.count_fields).rev() {
+                self.table.get_records_mut().remove_row(row);
+            }
+
+            self.records.remove(pos);
+            self.headers.remove(pos);
         }
+
+        self.table.destroy_width_cache();
+        self.table.destroy_height_cache();
+
+        self
     }
 
     /// Truncates table to a set width value for a table.
@@ -114,11 +253,10 @@ impl ExpandedDisplay {
     ///
     /// It tries to not affect fields, but if there's no enough space all records will be deleted and fields will be cut.
     ///
-    /// The minimum width is 14.
+    /// The minimum width is a width of a record header, which is 14 by default.
     pub fn truncate(&mut self, max: usize, suffix: &str) -> bool {
-        // -[ RECORD 0 ]-
-        let teplate_width = self.records.len().to_string().len() + 13;
-        let min_width = teplate_width;
+        let min_width = self.headers.iter().map(|s| string_width(s)).max();
+        let min_width = min_width.unwrap_or_default();
         if max < min_width {
             return false;
         }
@@ -130,10 +268,9 @@ impl ExpandedDisplay {
 
         let max = max - suffix_width;
 
-        let fields_max_width = self
-            .fields
-            .iter()
-            .map(|s| string_width(s))
+        let count_rows = self.table.count_rows();
+        let fields_max_width = (0..count_rows)
+            .map(|row| string_width(self.table.get_records().get_text((row, 0))))
             .max()
             .unwrap_or_default();
 
@@ -152,15 +289,41 @@ impl ExpandedDisplay {
 
             let max = max - suffix_width;
 
-            truncate_fields(&mut self.fields, max, suffix);
-            truncate_records(&mut self.records, 0, suffix);
+            self.truncate_column(0, max, suffix);
+            self.truncate_column(1, 0, suffix);
         } else {
             let max = max - fields_max_width - 3 - suffix_width;
-            truncate_records(&mut self.records, max, suffix);
+            self.truncate_column(1, max, suffix);
         }
 
         true
     }
+
+    fn truncate_column(&mut self, col: usize, max: usize, suffix: &str) {
+        let width_fn = CfgWidthFunction::from_cfg(self.table.get_config());
+        for row in 0..self.table.count_rows() {
+            let mut text = self.table.get_records().get_text((row, col)).to_owned();
+            truncate(&mut text, max, suffix);
+            self.table
+                .get_records_mut()
+                .set((row, col), text, &width_fn);
+        }
+
+        self.table.destroy_width_cache();
+        self.table.destroy_height_cache();
+    }
+
+    fn print_headers(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, header) in self.headers.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            f.write_str(header)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl std::fmt::Display for ExpandedDisplay {
@@ -169,35 +332,22 @@ impl std::fmt::Display for ExpandedDisplay {
             return Ok(());
         }
 
-        // It's possible that field|header can be a multiline string so
-        // we escape it and trim \" chars.
-        let fields = self.fields.iter().collect::<Vec<_>>();
-
-        let max_field_width = fields
-            .iter()
-            .map(|s| string_width(s))
-            .max()
-            .unwrap_or_default();
-
-        let max_values_length = self
-            .records
-            .iter()
-            .map(|record| record.iter().map(|s| string_width(s)).max())
-            .max()
-            .unwrap_or_default()
-            .unwrap_or_default();
+        if self.count_fields == 0 {
+            return self.print_headers(f);
+        }
 
-        for (i, records) in self.records.iter().enumerate() {
-            write_header_template(f, i, max_field_width, max_values_length)?;
+        let mut table = self.table.clone();
+        set_record_headers(&mut table, &self.headers, self.count_fields);
 
-            for (value, field) in records.iter().zip(fields.iter()) {
+        let output = table.to_string();
+        for (i, line) in output.lines().enumerate() {
+            if i > 0 {
                 writeln!(f)?;
-                write_record(f, field, value, max_field_width)?;
             }
 
-            let is_last_record = i + 1 == self.records.len();
-            if !is_last_record {
-                writeln!(f)?;
+            match self.keep_trailing_spaces {
+                true => f.write_str(line)?,
+                false => f.write_str(line.trim_end_matches(' '))?,
             }
         }
 
@@ -205,62 +355,70 @@ impl std::fmt::Display for ExpandedDisplay {
     }
 }
 
-fn truncate_records(records: &mut Vec<Vec<String>>, max_width: usize, suffix: &str) {
-    for fields in records {
-        truncate_fields(fields, max_width, suffix);
-    }
-}
+fn set_record_headers(table: &mut Table, headers: &[String], count_fields: usize) {
+    let (count_rows, count_cols) = table.shape();
+    let cfg = table.get_config_mut();
+    for (i, header) in headers.iter().enumerate() {
+        let row = i * count_fields;
+        if !cfg.has_horizontal(row, count_rows) {
+            cfg.set_horizontal_line(row, default_separator());
+        }
 
-fn truncate_fields(records: &mut Vec<String>, max_width: usize, suffix: &str) {
-    for text in records {
-        truncate(text, max_width, suffix);
+        cfg.override_split_line(row, header.clone(), Offset::Begin(0));
     }
-}
 
-fn write_header_template(
-    f: &mut std::fmt::Formatter<'_>,
-    index: usize,
-    max_field_width: usize,
-    max_values_length: usize,
-) -> std::fmt::Result {
-    let mut template = format!("-[ RECORD {index} ]-");
-    let default_template_length = template.len();
-
-    // 3 - is responsible for ' | ' formatting
-    let max_line_width = std::cmp::max(
-        max_field_width + 3 + max_values_length,
-        default_template_length,
-    );
-    let rest_to_print = max_line_width - default_template_length;
-    if rest_to_print > 0 {
-        // + 1 is a space after field name and we get a next pos so its +2
-        if max_field_width + 2 > default_template_length {
-            let part1 = (max_field_width + 1) - default_template_length;
-            let part2 = rest_to_print - part1 - 1;
-
-            template.extend(
-                std::iter::repeat('-')
-                    .take(part1)
-                    .chain(std::iter::once('+'))
-                    .chain(std::iter::repeat('-').take(part2)),
-            );
-        } else {
-            template.extend(std::iter::repeat('-').take(rest_to_print));
+    // a header may be wider then a table, so we make the last column wider to fit it.
+    let header_width = headers.iter().map(|s| string_width(s)).max();
+    let header_width = header_width.unwrap_or_default();
+    let table_width = table.total_width();
+    if header_width > table_width {
+        let cfg = table.get_config_mut();
+        for row in 0..count_rows {
+            let entity = Entity::Cell(row, count_cols - 1);
+            let mut padding = *cfg.get_padding(entity);
+            padding.right.size += header_width - table_width;
+            cfg.set_padding(entity, padding);
         }
-    }
 
-    write!(f, "{template}")?;
+        table.destroy_width_cache();
+    }
+}
 
-    Ok(())
+fn configure_table(table: &mut Table) {
+    let cfg = table.get_config_mut();
+    cfg.clear_theme();
+    cfg.set_borders(Borders {
+        vertical: Some('|'),
+        ..Default::default()
+    });
+
+    cfg.set_padding(
+        Entity::Column(0),
+        Padding {
+            left: Indent::default(),
+            right: Indent::spaced(1),
+            top: Indent::default(),
+            bottom: Indent::default(),
+        },
+    );
+    cfg.set_padding(
+        Entity::Column(1),
+        Padding {
+            left: Indent::spaced(1),
+            right: Indent::default(),
+            top: Indent::default(),
+            bottom: Indent::default(),
+        },
+    );
 }
 
-fn write_record(
-    f: &mut std::fmt::Formatter<'_>,
-    field: &str,
-    value: &str,
-    max_field_width: usize,
-) -> std::fmt::Result {
-    write!(f, "{field:max_field_width$} | {value}")
+fn default_separator() -> HorizontalLine<char> {
+    HorizontalLine {
+        main: Some('-'),
+        intersection: Some('+'),
+        left: None,
+        right: None,
+    }
 }
 
 fn truncate(text: &mut String, max: usize, suffix: &str) {
@@ -0,0 +1,155 @@
+//! This module contains a [`LiveDisplay`] structure which redraws a [`Table`] in place.
+//!
+//! [`Table`]: crate::Table
+
+use std::io::{self, Write};
+
+use papergrid::records::Records;
+
+use crate::{width::get_table_widths, Table, Width};
+
+/// `LiveDisplay` redraws a [`Table`] in place, for example to show a progress of some jobs.
+///
+/// It remembers the last printed frame and rewrites only the lines which were changed,
+/// moving a cursor by ANSI sequences, so a terminal doesn't flicker as it does on clearing a screen.
+///
+/// Widths of columns never shrink between frames (they are set by [`Width::list`]),
+/// so a table doesn't jump back and forth when a value gets shorter.
+///
+/// A cursor is expected to be left where the last frame ended,
+/// so nothing else must be printed in between.
+/// A frame higher than a terminal can't be redrawn correctly.
+///
+/// ```
+/// use tabled::{display::LiveDisplay, Style, Table};
+///
+/// let mut display = LiveDisplay::new(Vec::new());
+///
+/// let mut table = Table::new([("job", "10%")]);
+/// table.with(Style::psql());
+/// display.draw(&table).unwrap();
+///
+/// let mut table = Table::new([("job", "100%")]);
+/// table.with(Style::psql());
+/// display.draw(&table).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(display.into_inner()).unwrap(),
+///     concat!(
+///         " &str | &str \u{1b}[K\n",
+///         "------+------\u{1b}[K\n",
+///         " job  | 10%  \u{1b}[K\n",
+///         // the second frame
+///         "\u{1b}[1A",
+///         " job  | 100% \u{1b}[K\n",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct LiveDisplay<W> {
+    out: W,
+    lines: Vec<String>,
+    widths: Vec<usize>,
+}
+
+impl<W> LiveDisplay<W> {
+    /// Creates a new instance of `LiveDisplay` which writes to a given output.
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            lines: Vec::new(),
+            widths: Vec::new(),
+        }
+    }
+
+    /// Forgets the last frame and widths of columns,
+    /// so the next frame is printed from a current position of a cursor.
+    pub fn reset(&mut self) {
+        self.lines.clear();
+        self.widths.clear();
+    }
+
+    /// Returns a reference to an output.
+    pub fn get_ref(&self) -> &W {
+        &self.out
+    }
+
+    /// Returns a mutable reference to an output.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    /// Returns an output.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W> LiveDisplay<W>
+where
+    W: Write,
+{
+    /// Draws a table over the last frame.
+    pub fn draw<R>(&mut self, table: &Table<R>) -> io::Result<()>
+    where
+        R: Records + Clone,
+    {
+        let widths = get_table_widths(table.get_records(), table.get_config());
+        if widths.len() == self.widths.len() {
+            for (width, last) in widths.into_iter().zip(self.widths.iter_mut()) {
+                *last = std::cmp::max(width, *last);
+            }
+        } else {
+            self.widths = widths;
+        }
+
+        let mut table = table.clone();
+        table.with(Width::list(self.widths.iter().copied()));
+
+        let lines = table.to_string().lines().map(String::from).collect();
+        self.draw_lines(lines)
+    }
+
+    fn draw_lines(&mut self, lines: Vec<String>) -> io::Result<()> {
+        let first = match (0..std::cmp::max(lines.len(), self.lines.len()))
+            .find(|&i| lines.get(i) != self.lines.get(i))
+        {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+
+        let up = self.lines.len() - first;
+        if up > 0 {
+            write!(self.out, "\u{1b}[{}A", up)?;
+        }
+
+        let mut skipped = 0;
+        for (i, line) in lines.iter().enumerate().skip(first) {
+            if self.lines.get(i) == Some(line) {
+                skipped += 1;
+                continue;
+            }
+
+            if skipped > 0 {
+                write!(self.out, "\u{1b}[{}B", skipped)?;
+                skipped = 0;
+            }
+
+            writeln!(self.out, "{}\u{1b}[K", line)?;
+        }
+
+        if skipped > 0 {
+            write!(self.out, "\u{1b}[{}B", skipped)?;
+        }
+
+        // the rest of the last frame is erased
+        if lines.len() < self.lines.len() {
+            write!(self.out, "\u{1b}[J")?;
+        }
+
+        self.lines = lines;
+        self.out.flush()
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! [`Table`]: crate::Table
 
+mod auto_display;
 mod expanded_display;
+mod live_display;
 
+pub use auto_display::*;
 pub use expanded_display::*;
+pub use live_display::*;
@@ -0,0 +1,334 @@
+//! This module contains [`Bar`] and [`Sparkline`] structures, which render numeric cells as charts.
+//!
+//! Both are [`CellOption`]s, so they're applied by [`Modify`].
+//! A cell which can't be parsed as a number (like a header) is left intact.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     chart::{Bar, Sparkline},
+//!     object::{Columns, Object, Rows},
+//!     Modify, Table,
+//! };
+//!
+//! let data = [("cpu", "75", "1 3 5 7 4"), ("mem", "30", "2 2 3 4 8")];
+//!
+//! let table = Table::new(data)
+//!     .with(Modify::new(Columns::single(1).not(Rows::first())).with(Bar::percent().width(8)))
+//!     .with(Modify::new(Columns::single(2).not(Rows::first())).with(Sparkline::new()))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------+----------+-------+\n",
+//!         "| &str | &str     | &str  |\n",
+//!         "+------+----------+-------+\n",
+//!         "| cpu  | ███  75% | ▁▃▆█▅ |\n",
+//!         "+------+----------+-------+\n",
+//!         "| mem  | █▎   30% | ▁▁▂▃█ |\n",
+//!         "+------+----------+-------+",
+//!     )
+//! );
+//! ```
+//!
+//! [`CellOption`]: crate::CellOption
+//! [`Modify`]: crate::Modify
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    util::string_width,
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{
+    width::{get_cell_width, get_table_widths},
+    CellOption, Table,
+};
+
+#[cfg(feature = "color")]
+use crate::color::Color;
+
+const BAR_FULL: char = '█';
+const BAR_PARTS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// [`Bar`] renders a number as a horizontal bar with eighth-block precision.
+///
+/// A bar shows a ratio of a value to a max value.
+/// By default a bar fills the whole width of a column (without padding),
+/// so it must be set after all options which change widths,
+/// like [`Width::wrap`] or [`Justify`].
+/// Otherwise a width can be set by [`Bar::width`].
+///
+/// ```
+/// use tabled::{chart::Bar, object::Segment, Modify, Table};
+///
+/// let table = Table::new(["100", "50", "3"])
+///     .with(Modify::new(Segment::all()).with(Bar::new(100.0)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------+\n",
+///         "| &str |\n",
+///         "+------+\n",
+///         "| ████ |\n",
+///         "+------+\n",
+///         "| ██   |\n",
+///         "+------+\n",
+///         "| ▏    |\n",
+///         "+------+",
+///     )
+/// );
+/// ```
+///
+/// [`Width::wrap`]: crate::Width::wrap
+/// [`Justify`]: crate::width::Justify
+#[derive(Debug, Clone)]
+pub struct Bar {
+    max: f64,
+    width: Option<usize>,
+    label: bool,
+    percent: bool,
+    #[cfg(feature = "color")]
+    thresholds: Vec<(f64, Color)>,
+}
+
+impl Bar {
+    /// Creates a [`Bar`] for values from 0 to a given max.
+    pub fn new(max: f64) -> Self {
+        Self {
+            max,
+            width: None,
+            label: false,
+            percent: false,
+            #[cfg(feature = "color")]
+            thresholds: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Bar`] for percentages, which shows a value after a bar.
+    ///
+    /// A value may have a `%` suffix.
+    pub fn percent() -> Self {
+        Self {
+            label: true,
+            percent: true,
+            ..Self::new(100.0)
+        }
+    }
+
+    /// Sets a width of a bar including a label.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets whether a value is shown after a bar.
+    pub fn label(mut self, label: bool) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Sets a color of a bar for values which are greater than or equal to a given one.
+    ///
+    /// If several thresholds are reached the greatest one is used.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn threshold(mut self, value: f64, color: Color) -> Self {
+        self.thresholds.push((value, color));
+        self
+    }
+
+    fn render(&self, text: &str, width: usize) -> Option<String> {
+        let text = text.trim();
+        let value = match self.percent {
+            true => text.strip_suffix('%').unwrap_or(text),
+            false => text,
+        };
+        let value = value.trim().parse::<f64>().ok()?;
+
+        let label = match (self.label, self.percent) {
+            (true, true) => format!(" {}%", text.trim_end_matches('%').trim()),
+            (true, false) => format!(" {}", text),
+            (false, _) => String::new(),
+        };
+
+        let width = width.saturating_sub(string_width(&label));
+        let bar = build_bar(value, self.max, width);
+        let bar_width = string_width(&bar);
+
+        #[cfg(feature = "color")]
+        let bar = match self.get_color(value) {
+            Some(color) => {
+                let color = papergrid::AnsiColor::from(color.clone());
+                format!("{}{}{}", color.get_prefix(), bar, color.get_suffix())
+            }
+            None => bar,
+        };
+
+        let padding = " ".repeat(width - bar_width);
+
+        Some(format!("{}{}{}", bar, padding, label))
+    }
+
+    #[cfg(feature = "color")]
+    fn get_color(&self, value: f64) -> Option<&Color> {
+        self.thresholds
+            .iter()
+            .filter(|(threshold, _)| value >= *threshold)
+            .max_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(_, color)| color)
+    }
+}
+
+impl<R> CellOption<R> for Bar
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let widths = match table.get_width_cache() {
+            Some(widths) => widths.to_vec(),
+            None => get_table_widths(table.get_records(), table.get_config()),
+        };
+
+        // columns are recalculated only when a bar overflows its cell
+        let mut is_overflown = false;
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let cell_width = get_cell_width(table, &widths, pos);
+            let width = self.width.unwrap_or(cell_width);
+
+            let text = table.get_records().get_text(pos);
+            if let Some(text) = self.render(text, width) {
+                is_overflown |= string_width(&text) > cell_width;
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+
+        if is_overflown {
+            table.destroy_width_cache();
+        }
+
+        table.destroy_height_cache();
+    }
+}
+
+/// [`Sparkline`] renders a series of numbers as a sparkline.
+///
+/// Numbers in a cell are separated by whitespaces or commas.
+/// Each number is shown by a single char, scaled from the min to the max value of the series,
+/// unless a range is set by [`Sparkline::range`].
+///
+/// ```
+/// use tabled::{chart::Sparkline, object::Segment, Modify, Table};
+///
+/// let table = Table::new(["1, 2, 3, 4, 5, 6, 7, 8"])
+///     .with(Modify::new(Segment::all()).with(Sparkline::new()))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+----------+\n",
+///         "| &str     |\n",
+///         "+----------+\n",
+///         "| ▁▂▃▄▅▆▇█ |\n",
+///         "+----------+",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Sparkline {
+    range: Option<(f64, f64)>,
+}
+
+impl Sparkline {
+    /// Creates a [`Sparkline`] scaled by a series.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a range of values, so series of different cells are comparable.
+    ///
+    /// Values out of the range are clamped.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    fn render(&self, text: &str) -> Option<String> {
+        let values = text
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse::<f64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        if values.is_empty() {
+            return None;
+        }
+
+        let (min, max) = self.range.unwrap_or_else(|| {
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            (min, max)
+        });
+
+        let last = SPARKS.len() - 1;
+        let sparkline = values
+            .into_iter()
+            .map(|value| {
+                let ratio = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.0
+                };
+
+                let level = (ratio.clamp(0.0, 1.0) * last as f64).round() as usize;
+                SPARKS[level]
+            })
+            .collect();
+
+        Some(sparkline)
+    }
+}
+
+impl<R> CellOption<R> for Sparkline
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            if let Some(text) = self.render(text) {
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn build_bar(value: f64, max: f64, width: usize) -> String {
+    let ratio = if max > 0.0 { value / max } else { 0.0 };
+    let ratio = ratio.clamp(0.0, 1.0);
+
+    let eighths = (ratio * (width * 8) as f64).round() as usize;
+    let (full, part) = (eighths / 8, eighths % 8);
+
+    let mut bar = BAR_FULL.to_string().repeat(full);
+    if part > 0 {
+        bar.push(BAR_PARTS[part - 1]);
+    }
+
+    bar
+}
@@ -6,7 +6,7 @@ use std::{borrow::Cow, convert::TryFrom};
 
 use papergrid::{records::Records, AnsiColor, Entity};
 
-use crate::{CellOption, Table, TableOption};
+use crate::{rich::TextColor, CellOption, Table, TableOption};
 
 /// Color represents a color which can be set to things like [`Border`], [`Padding`] and [`Margin`].
 ///
@@ -175,6 +175,36 @@ impl Color {
     pub fn new(prefix: String, suffix: String) -> Self {
         Self(AnsiColor::new(prefix.into(), suffix.into()))
     }
+
+    /// Creates a foreground [`Color`] from a structured [`TextColor`].
+    ///
+    /// ```
+    /// use tabled::{color::Color, rich::TextColor};
+    ///
+    /// let color = Color::fg(TextColor::Rgb(255, 0, 0));
+    /// ```
+    pub fn fg(color: TextColor) -> Self {
+        Self::from_text_color(color, false)
+    }
+
+    /// Creates a background [`Color`] from a structured [`TextColor`].
+    pub fn bg(color: TextColor) -> Self {
+        Self::from_text_color(color, true)
+    }
+
+    fn from_text_color(color: TextColor, is_background: bool) -> Self {
+        let mut prefix = String::from("\u{1b}[");
+        color.fmt_ansi(&mut prefix, is_background);
+        prefix.push('m');
+
+        let suffix = if is_background {
+            "\u{1b}[49m"
+        } else {
+            "\u{1b}[39m"
+        };
+
+        Self::new(prefix, suffix.to_owned())
+    }
 }
 
 impl From<Color> for AnsiColor<'static> {
@@ -0,0 +1,329 @@
+//! This module contains [`ColorDepth`] structure, used to adapt colors of a [`Table`]
+//! to the capabilities of a terminal.
+//!
+//! [`Table`]: crate::Table
+
+use std::borrow::Cow;
+
+use crate::{
+    rich::{fixed_to_rgb, TextColor},
+    Table, TableOption,
+};
+
+/// ColorDepth declares which colors a terminal is able to show.
+///
+/// Being set to a [`Table`] it converts all colors on rendering
+/// (in cells, borders, paddings and margins alike):
+///
+/// - 24-bit colors are mapped to the closest 256-palette colors on [`ColorDepth::Ansi256`];
+/// - 24-bit and 256-palette colors are mapped to the closest named colors on [`ColorDepth::Ansi16`];
+/// - all styling is stripped on [`ColorDepth::NoColor`].
+///
+/// You can use [`ColorDepth::from_env`] to respect `NO_COLOR`, `CLICOLOR` and alike variables.
+///
+/// ## Example
+///
+#[cfg_attr(feature = "color", doc = "```")]
+#[cfg_attr(not(feature = "color"), doc = "```ignore")]
+/// use tabled::{color_depth::ColorDepth, rich::{RichText, TextColor, TextStyle}, Style, Table};
+///
+/// let text = RichText::styled("Hello", TextStyle::new().fg(TextColor::Rgb(250, 10, 10)));
+///
+/// let table = Table::new([text.to_ansi()])
+///     .with(Style::empty())
+///     .with(ColorDepth::Ansi16)
+///     .to_string();
+///
+/// assert_eq!(table, " String \n \u{1b}[91mHello\u{1b}[0m  ");
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// No colors and styles at all.
+    NoColor,
+    /// 16 named colors.
+    Ansi16,
+    /// 256-palette colors.
+    Ansi256,
+    /// 24-bit colors.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects a color depth by environment variables.
+    ///
+    /// See [`ColorDepth::detect`].
+    pub fn from_env() -> Self {
+        Self::detect(|name| std::env::var(name).ok())
+    }
+
+    /// Detects a color depth by variables returned from a given function.
+    ///
+    /// - a not empty `NO_COLOR` disables colors;
+    /// - `CLICOLOR=0` disables colors unless `CLICOLOR_FORCE` is set to not `0`;
+    /// - `COLORTERM=truecolor` or `COLORTERM=24bit` enables 24-bit colors;
+    /// - `TERM` containing `256` enables 256-palette colors, and `TERM=dumb` disables colors;
+    /// - otherwise 16 colors are expected.
+    ///
+    /// Notice that it doesn't check whether an output is a terminal.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::color_depth::ColorDepth;
+    ///
+    /// let depth = ColorDepth::detect(|name| match name {
+    ///     "TERM" => Some(String::from("xterm-256color")),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(depth, ColorDepth::Ansi256);
+    /// ```
+    pub fn detect<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let is_set = |name| matches!(var(name), Some(value) if !value.is_empty());
+        if is_set("NO_COLOR") {
+            return Self::NoColor;
+        }
+
+        let is_forced =
+            matches!(var("CLICOLOR_FORCE"), Some(value) if !value.is_empty() && value != "0");
+        if !is_forced && var("CLICOLOR").as_deref() == Some("0") {
+            return Self::NoColor;
+        }
+
+        let colorterm = var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return Self::TrueColor;
+        }
+
+        let term = var("TERM").unwrap_or_default();
+        if term.contains("truecolor") || term.contains("24bit") || term.contains("direct") {
+            return Self::TrueColor;
+        }
+
+        if term.contains("256") {
+            return Self::Ansi256;
+        }
+
+        if term == "dumb" && !is_forced {
+            return Self::NoColor;
+        }
+
+        Self::Ansi16
+    }
+
+    /// Maps a color to the closest one which can be shown with the depth.
+    ///
+    /// It returns [`None`] in case of [`ColorDepth::NoColor`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::{color_depth::ColorDepth, rich::TextColor};
+    ///
+    /// assert_eq!(ColorDepth::Ansi256.downgrade(TextColor::Rgb(255, 0, 0)), Some(TextColor::Fixed(196)));
+    /// assert_eq!(ColorDepth::Ansi16.downgrade(TextColor::Fixed(196)), Some(TextColor::BrightRed));
+    /// assert_eq!(ColorDepth::NoColor.downgrade(TextColor::Red), None);
+    /// ```
+    pub fn downgrade(self, color: TextColor) -> Option<TextColor> {
+        match self {
+            Self::NoColor => None,
+            Self::TrueColor => Some(color),
+            Self::Ansi256 => match color {
+                TextColor::Rgb(r, g, b) => Some(TextColor::Fixed(rgb_to_fixed(r, g, b))),
+                color => Some(color),
+            },
+            Self::Ansi16 => match color {
+                TextColor::Fixed(n) if n < 16 => Some(NAMED[n as usize]),
+                TextColor::Fixed(_) | TextColor::Rgb(..) => Some(rgb_to_named(color.to_rgb())),
+                color => Some(color),
+            },
+        }
+    }
+
+    /// Converts all SGR (color and style) sequences in a text to the depth.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::color_depth::ColorDepth;
+    ///
+    /// let text = "\u{1b}[1;38;2;0;0;250mHello\u{1b}[0m";
+    ///
+    /// assert_eq!(ColorDepth::Ansi256.convert(text), "\u{1b}[1;38;5;21mHello\u{1b}[0m");
+    /// assert_eq!(ColorDepth::NoColor.convert(text), "Hello");
+    /// ```
+    pub fn convert(self, text: &str) -> Cow<'_, str> {
+        if self == Self::TrueColor || !text.contains('\u{1b}') {
+            return Cow::Borrowed(text);
+        }
+
+        let mut buf = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("\u{1b}[") {
+            buf.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let params_len = rest[2..]
+                .find(|c: char| !c.is_ascii_digit() && c != ';')
+                .unwrap_or(rest.len() - 2);
+            let end = 2 + params_len;
+            if !rest[end..].starts_with('m') {
+                buf.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+
+            let params = &rest[2..end];
+            rest = &rest[end + 1..];
+
+            if self == Self::NoColor {
+                continue;
+            }
+
+            let converted = self.convert_sgr(params);
+            buf.push_str("\u{1b}[");
+            buf.push_str(converted.as_deref().unwrap_or(params));
+            buf.push('m');
+        }
+
+        buf.push_str(rest);
+
+        Cow::Owned(buf)
+    }
+
+    fn convert_sgr(self, params: &str) -> Option<String> {
+        let codes = params
+            .split(';')
+            .map(|code| match code {
+                "" => Some(0),
+                code => code.parse::<u8>().ok(),
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut buf = String::new();
+        let mut i = 0;
+        while i < codes.len() {
+            if !buf.is_empty() {
+                buf.push(';');
+            }
+
+            let code = codes[i];
+            let is_background = code == 48;
+            if code != 38 && code != 48 {
+                buf.push_str(&code.to_string());
+                i += 1;
+                continue;
+            }
+
+            let (color, len) = match codes.get(i + 1..) {
+                Some([5, n, ..]) => (TextColor::Fixed(*n), 3),
+                Some([2, r, g, b, ..]) => (TextColor::Rgb(*r, *g, *b), 5),
+                _ => return None,
+            };
+
+            match self.downgrade(color) {
+                Some(color) => color.fmt_ansi(&mut buf, is_background),
+                None => buf.push_str(if is_background { "49" } else { "39" }),
+            }
+
+            i += len;
+        }
+
+        Some(buf)
+    }
+}
+
+impl<R> TableOption<R> for ColorDepth {
+    fn change(&mut self, table: &mut Table<R>) {
+        table.set_color_depth(*self);
+    }
+}
+
+const NAMED: [TextColor; 16] = [
+    TextColor::Black,
+    TextColor::Red,
+    TextColor::Green,
+    TextColor::Yellow,
+    TextColor::Blue,
+    TextColor::Magenta,
+    TextColor::Cyan,
+    TextColor::White,
+    TextColor::BrightBlack,
+    TextColor::BrightRed,
+    TextColor::BrightGreen,
+    TextColor::BrightYellow,
+    TextColor::BrightBlue,
+    TextColor::BrightMagenta,
+    TextColor::BrightCyan,
+    TextColor::BrightWhite,
+];
+
+fn rgb_to_fixed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        v => (v - 35) / 40,
+    };
+
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let avg = (r as usize + g as usize + b as usize) / 3;
+    let gray = 232 + std::cmp::min(23, avg.saturating_sub(3) / 10) as u8;
+
+    if distance((r, g, b), fixed_to_rgb(gray)) < distance((r, g, b), fixed_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn rgb_to_named(rgb: (u8, u8, u8)) -> TextColor {
+    (0..16)
+        .min_by_key(|&n| distance(rgb, fixed_to_rgb(n)))
+        .map(|n| NAMED[n as usize])
+        .unwrap_or(TextColor::White)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> usize {
+    let diff = |a: u8, b: u8| (a as isize - b as isize).unsigned_abs();
+    let (r, g, b) = (diff(a.0, b.0), diff(a.1, b.1), diff(a.2, b.2));
+    r * r + g * g + b * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb_to_fixed_test() {
+        assert_eq!(rgb_to_fixed(0, 0, 0), 16);
+        assert_eq!(rgb_to_fixed(255, 255, 255), 231);
+        assert_eq!(rgb_to_fixed(255, 0, 0), 196);
+        assert_eq!(rgb_to_fixed(128, 128, 128), 244);
+        assert_eq!(rgb_to_fixed(95, 135, 175), 67);
+    }
+
+    #[test]
+    fn rgb_to_named_test() {
+        assert_eq!(rgb_to_named((250, 10, 10)), TextColor::BrightRed);
+        assert_eq!(rgb_to_named((10, 10, 10)), TextColor::Black);
+        assert_eq!(rgb_to_named((0, 190, 190)), TextColor::Cyan);
+    }
+
+    #[test]
+    fn convert_keeps_unknown_sequences_test() {
+        let depth = ColorDepth::Ansi16;
+        assert_eq!(depth.convert("\u{1b}[2Khello"), "\u{1b}[2Khello");
+        assert_eq!(depth.convert("\u{1b}[38;5mhello"), "\u{1b}[38;5mhello");
+        assert_eq!(
+            depth.convert("\u{1b}[1;48;5;196mhello"),
+            "\u{1b}[1;101mhello"
+        );
+        assert_eq!(depth.convert("\u{1b}[mhello"), "\u{1b}[0mhello");
+    }
+}
@@ -0,0 +1,461 @@
+//! This module contains primitives for a conditional formatting of a [`Table`].
+//!
+//! - [`Matching`] is an [`Object`] which selects only cells satisfying a [`Condition`],
+//!   so any option can be applied through [`Modify`] or [`Highlight`].
+//! - [`ColorScale`] colors numeric cells by a gradient.
+//!
+//! [`Table`]: crate::Table
+//! [`Modify`]: crate::Modify
+//! [`Highlight`]: crate::Highlight
+
+use std::collections::HashSet;
+
+use papergrid::records::Records;
+
+use crate::{
+    object::{Entity, Object},
+    Table,
+};
+
+#[cfg(feature = "color")]
+use papergrid::{records::RecordsMut, width::CfgWidthFunction, Position};
+
+#[cfg(feature = "color")]
+use crate::{rich::TextColor, CellOption};
+
+/// A condition which is checked against a text of a cell.
+///
+/// It's implemented for any `Fn(&str) -> bool`,
+/// so for example a regex can be used as `|text: &str| re.is_match(text)`.
+pub trait Condition {
+    /// Verifies whether a text satisfies the condition.
+    fn check(&self, text: &str) -> bool;
+}
+
+impl<F> Condition for F
+where
+    F: Fn(&str) -> bool,
+{
+    fn check(&self, text: &str) -> bool {
+        (self)(text)
+    }
+}
+
+/// A [`Condition`] which compares a numeric value of a cell.
+///
+/// A text which is not a number never satisfies the condition.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{conditional::Number, conditional::Condition};
+///
+/// assert!(Number::gt(90.0).check("91.5"));
+/// assert!(!Number::gt(90.0).check("90"));
+/// assert!(Number::between(1.0, 10.0).check(" 10 "));
+/// assert!(!Number::lt(0.0).check("N/A"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number {
+    min: Option<(f64, bool)>,
+    max: Option<(f64, bool)>,
+}
+
+impl Number {
+    /// A value is greater than a given one.
+    pub fn gt(value: f64) -> Self {
+        Self::new(Some((value, false)), None)
+    }
+
+    /// A value is greater than or equal to a given one.
+    pub fn ge(value: f64) -> Self {
+        Self::new(Some((value, true)), None)
+    }
+
+    /// A value is less than a given one.
+    pub fn lt(value: f64) -> Self {
+        Self::new(None, Some((value, false)))
+    }
+
+    /// A value is less than or equal to a given one.
+    pub fn le(value: f64) -> Self {
+        Self::new(None, Some((value, true)))
+    }
+
+    /// A value is equal to a given one.
+    pub fn eq(value: f64) -> Self {
+        Self::between(value, value)
+    }
+
+    /// A value is in a given inclusive range.
+    pub fn between(min: f64, max: f64) -> Self {
+        Self::new(Some((min, true)), Some((max, true)))
+    }
+
+    fn new(min: Option<(f64, bool)>, max: Option<(f64, bool)>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl Condition for Number {
+    fn check(&self, text: &str) -> bool {
+        let value = match parse_number(text) {
+            Some(value) => value,
+            None => return false,
+        };
+
+        let is_above = match self.min {
+            Some((min, true)) => value >= min,
+            Some((min, false)) => value > min,
+            None => true,
+        };
+
+        let is_below = match self.max {
+            Some((max, true)) => value <= max,
+            Some((max, false)) => value < max,
+            None => true,
+        };
+
+        is_above && is_below
+    }
+}
+
+/// A [`Condition`] which checks a text of a cell.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{conditional::Text, conditional::Condition};
+///
+/// assert!(Text::contains("err").check("an error"));
+/// assert!(Text::starts_with("an").check("an error"));
+/// assert!(!Text::equals("error").check("an error"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Text<S> {
+    text: S,
+    kind: TextCheck,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextCheck {
+    Equals,
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+impl<S> Text<S>
+where
+    S: AsRef<str>,
+{
+    /// A text is equal to a given one.
+    pub fn equals(text: S) -> Self {
+        Self::new(text, TextCheck::Equals)
+    }
+
+    /// A text contains a given one.
+    pub fn contains(text: S) -> Self {
+        Self::new(text, TextCheck::Contains)
+    }
+
+    /// A text starts with a given one.
+    pub fn starts_with(text: S) -> Self {
+        Self::new(text, TextCheck::StartsWith)
+    }
+
+    /// A text ends with a given one.
+    pub fn ends_with(text: S) -> Self {
+        Self::new(text, TextCheck::EndsWith)
+    }
+
+    fn new(text: S, kind: TextCheck) -> Self {
+        Self { text, kind }
+    }
+}
+
+impl<S> Condition for Text<S>
+where
+    S: AsRef<str>,
+{
+    fn check(&self, text: &str) -> bool {
+        let pattern = self.text.as_ref();
+        match self.kind {
+            TextCheck::Equals => text == pattern,
+            TextCheck::Contains => text.contains(pattern),
+            TextCheck::StartsWith => text.starts_with(pattern),
+            TextCheck::EndsWith => text.ends_with(pattern),
+        }
+    }
+}
+
+/// Matching is an [`Object`] which selects cells of a given [`Object`]
+/// whose text satisfies a [`Condition`].
+///
+/// The condition is checked at the moment the object is applied,
+/// so it sees all changes done before.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{
+///     conditional::{Matching, Number},
+///     object::Columns,
+///     Alignment, Modify, Style, Table,
+/// };
+///
+/// let data = [("Alice", 95), ("Bob", 70), ("Eve", 100)];
+///
+/// let table = Table::new(data)
+///     .with(Style::markdown())
+///     .with(Modify::new(Matching::new(Columns::single(1), Number::gt(90.0))).with(Alignment::right()))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "| &str  | i32 |\n",
+///         "|-------|-----|\n",
+///         "| Alice |  95 |\n",
+///         "| Bob   | 70  |\n",
+///         "| Eve   | 100 |",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Matching<O, C> {
+    object: O,
+    condition: C,
+}
+
+impl<O, C> Matching<O, C>
+where
+    O: Object,
+    C: Condition,
+{
+    /// Creates a new [`Matching`] object.
+    pub fn new(object: O, condition: C) -> Self {
+        Self { object, condition }
+    }
+}
+
+impl<O, C> Object for Matching<O, C>
+where
+    O: Object,
+    C: Condition,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells<R>(&self, table: &Table<R>) -> Self::Iter
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+        let records = table.get_records();
+
+        let mut seen = HashSet::new();
+        let mut cells = Vec::new();
+        for entity in self.object.cells(table) {
+            for pos in entity.iter(count_rows, count_cols) {
+                if seen.insert(pos) && self.condition.check(records.get_text(pos)) {
+                    cells.push(Entity::Cell(pos.0, pos.1));
+                }
+            }
+        }
+
+        cells.into_iter()
+    }
+}
+
+/// ColorScale colors numeric cells by a gradient between 2 colors.
+///
+/// By default the bounds of the scale are the minimum and maximum numbers
+/// among all cells the option is applied to, but they can be set by [`ColorScale::range`].
+/// Non numeric cells are left untouched.
+///
+/// A cell which was already colored by a [`ColorScale`] is recolored rather than wrapped in one more color.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{
+///     conditional::ColorScale,
+///     object::Columns,
+///     rich::TextColor,
+///     Modify, Style, Table,
+/// };
+///
+/// let data = [("Alice", 0), ("Bob", 50), ("Eve", 100)];
+///
+/// let table = Table::new(data)
+///     .with(Style::empty())
+///     .with(
+///         Modify::new(Columns::single(1))
+///             .with(ColorScale::new(TextColor::Rgb(0, 0, 0), TextColor::Rgb(200, 100, 0)))
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str   i32 \n",
+///         " Alice  \u{1b}[38;2;0;0;0m0\u{1b}[39m   \n",
+///         " Bob    \u{1b}[38;2;100;50;0m50\u{1b}[39m  \n",
+///         " Eve    \u{1b}[38;2;200;100;0m100\u{1b}[39m ",
+///     )
+/// );
+/// ```
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorScale {
+    from: TextColor,
+    to: TextColor,
+    range: Option<(f64, f64)>,
+    background: bool,
+    // cells colored so far, as a position, a value, an original text and a colored text.
+    cells: Vec<(Position, f64, String, String)>,
+}
+
+#[cfg(feature = "color")]
+impl ColorScale {
+    /// Creates a gradient from a color of the lowest value to a color of the highest value.
+    pub fn new(from: TextColor, to: TextColor) -> Self {
+        Self {
+            from,
+            to,
+            range: None,
+            background: false,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Sets fixed bounds of the scale.
+    ///
+    /// Values out of the range get the color of the closest bound.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Colors a background instead of a text.
+    pub fn background(mut self) -> Self {
+        self.background = true;
+        self
+    }
+
+    fn color(&self, value: f64, (min, max): (f64, f64)) -> TextColor {
+        let ratio = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let (r1, g1, b1) = self.from.to_rgb();
+        let (r2, g2, b2) = self.to.to_rgb();
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+
+        TextColor::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+}
+
+#[cfg(feature = "color")]
+impl<R> CellOption<R> for ColorScale
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        // Modify calls the option for each target entity separately,
+        // so the cells colored before are recolored when the range changes.
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            if self.cells.iter().any(|(p, ..)| *p == pos) {
+                continue;
+            }
+
+            let text = remove_scale_color(table.get_records().get_text(pos), self.background);
+            if let Some(value) = parse_number(&ansi_str::AnsiStr::ansi_strip(&text)) {
+                self.cells.push((pos, value, text, String::new()));
+            }
+        }
+
+        let range = self.range.unwrap_or_else(|| {
+            self.cells
+                .iter()
+                .fold((f64::MAX, f64::MIN), |(min, max), &(_, v, ..)| {
+                    (min.min(v), max.max(v))
+                })
+        });
+
+        let reset = if self.background {
+            "\u{1b}[49m"
+        } else {
+            "\u{1b}[39m"
+        };
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        for i in 0..self.cells.len() {
+            let (pos, value, original, colored) = &self.cells[i];
+
+            // a cell changed by another option since then is left as it is.
+            if !colored.is_empty() && table.get_records().get_text(*pos) != colored {
+                continue;
+            }
+
+            let mut text = String::from("\u{1b}[");
+            self.color(*value, range)
+                .fmt_ansi(&mut text, self.background);
+            text.push('m');
+            text.push_str(original);
+            text.push_str(reset);
+
+            if &text == colored {
+                continue;
+            }
+
+            let pos = *pos;
+            table.get_records_mut().set(pos, text.clone(), &width_fn);
+            self.cells[i].3 = text;
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Removes colors set by a [`ColorScale`] of a given kind,
+/// keeping colors of the other kind in place.
+#[cfg(feature = "color")]
+fn remove_scale_color(text: &str, background: bool) -> String {
+    let layers = [
+        (false, "\u{1b}[38;2;", "\u{1b}[39m"),
+        (true, "\u{1b}[48;2;", "\u{1b}[49m"),
+    ];
+    for (is_background, prefix, reset) in layers {
+        let inner = text
+            .strip_prefix(prefix)
+            .and_then(|text| text.strip_suffix(reset))
+            .and_then(|text| text.find('m').map(|i| (&text[..i], &text[i + 1..])));
+
+        let (code, inner) = match inner {
+            Some(inner) => inner,
+            None => continue,
+        };
+
+        let inner = remove_scale_color(inner, background);
+        if is_background == background {
+            return inner;
+        }
+
+        return format!("{}{}m{}{}", prefix, code, inner, reset);
+    }
+
+    text.to_owned()
+}
+
+fn parse_number(text: &str) -> Option<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| !value.is_nan())
+}
@@ -147,6 +147,12 @@ impl<L> Disable<L, TargetRow> {
     }
 }
 
+impl<L, Target> Disable<L, Target> {
+    pub(crate) fn get_locator_mut(&mut self) -> &mut L {
+        &mut self.locator
+    }
+}
+
 /// A marker struct for [`Disable`].
 #[derive(Debug)]
 pub struct TargetRow;
@@ -0,0 +1,413 @@
+//! This module contains a [`GroupBy`] structure which groups rows of a [`Table`]
+//! into sections, with optional subtotals and a grand total.
+//!
+//! [`Table`]: crate::Table
+
+use std::collections::HashMap;
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+    Entity, GridConfig,
+};
+
+use crate::{Table, TableOption};
+
+/// GroupBy groups rows by values of given columns.
+///
+/// Groups go in the order of the first appearance of their values,
+/// and each group is preceded by a section header row spanned over the whole table.
+///
+/// Additionally each group can be followed by a subtotal row
+/// and the table can be ended by a grand total row,
+/// which aggregate columns set by [`GroupBy::aggregate`].
+///
+/// A header of a table (see [`Table::has_header`]) and its groups are kept at their places.
+/// Settings of cells like alignment or padding are moved along with their rows,
+/// while spans set in the grouped rows are removed.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{group_by::{Aggregate, GroupBy}, Style, Table};
+///
+/// let data = [
+///     ("Fruit", "Apple", 3),
+///     ("Vegetable", "Carrot", 10),
+///     ("Fruit", "Pear", 4),
+/// ];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(GroupBy::new([0]).aggregate(2, Aggregate::Sum).subtotals().total())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "┌───────────┬────────┬─────┐\n",
+///         "│ &str      │ &str   │ i32 │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Fruit                    │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Fruit     │ Apple  │ 3   │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Fruit     │ Pear   │ 4   │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Subtotal  │        │ 7   │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Vegetable                │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Vegetable │ Carrot │ 10  │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Subtotal  │        │ 10  │\n",
+///         "├───────────┼────────┼─────┤\n",
+///         "│ Total     │        │ 17  │\n",
+///         "└───────────┴────────┴─────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Table::has_header`]: crate::Table::has_header
+#[derive(Debug, Clone)]
+pub struct GroupBy<F> {
+    columns: Vec<usize>,
+    header: F,
+    aggregates: Vec<(usize, Aggregate)>,
+    subtotals: bool,
+    total: bool,
+    labels: (String, String),
+}
+
+impl GroupBy<fn(&[&str]) -> String> {
+    /// Creates a [`GroupBy`] by values of given columns.
+    ///
+    /// By default a section header is the values joined by `", "`.
+    pub fn new<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            columns: columns.into_iter().collect(),
+            header: |values| values.join(", "),
+            aggregates: Vec::new(),
+            subtotals: false,
+            total: false,
+            labels: (String::from("Subtotal"), String::from("Total")),
+        }
+    }
+}
+
+impl<F> GroupBy<F> {
+    /// Sets a function which creates a section header from the values of a group.
+    pub fn header<H>(self, header: H) -> GroupBy<H>
+    where
+        H: Fn(&[&str]) -> String,
+    {
+        GroupBy {
+            columns: self.columns,
+            header,
+            aggregates: self.aggregates,
+            subtotals: self.subtotals,
+            total: self.total,
+            labels: self.labels,
+        }
+    }
+
+    /// Sets an aggregation of a column for subtotal and total rows.
+    pub fn aggregate(mut self, column: usize, aggregate: Aggregate) -> Self {
+        self.aggregates.retain(|&(col, _)| col != column);
+        self.aggregates.push((column, aggregate));
+        self
+    }
+
+    /// Adds a subtotal row after each group.
+    pub fn subtotals(mut self) -> Self {
+        self.subtotals = true;
+        self
+    }
+
+    /// Adds a grand total row at the end of a table.
+    pub fn total(mut self) -> Self {
+        self.total = true;
+        self
+    }
+
+    /// Sets labels of subtotal and total rows.
+    ///
+    /// A label is put into the first column which is not aggregated.
+    pub fn labels<S, T>(mut self, subtotal: S, total: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.labels = (subtotal.into(), total.into());
+        self
+    }
+
+    fn create_aggregate_row(
+        &self,
+        label: &str,
+        rows: &[&Vec<String>],
+        count_cols: usize,
+    ) -> Vec<String> {
+        let mut row = vec![String::new(); count_cols];
+
+        let label_column =
+            (0..count_cols).find(|col| !self.aggregates.iter().any(|(c, _)| c == col));
+        if let Some(col) = label_column {
+            row[col] = label.to_owned();
+        }
+
+        for &(col, aggregate) in &self.aggregates {
+            let values = rows.iter().map(|row| row[col].as_str());
+            row[col] = aggregate.calculate(values);
+        }
+
+        row
+    }
+}
+
+impl<F, R> TableOption<R> for GroupBy<F>
+where
+    F: Fn(&[&str]) -> String,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        if count_cols == 0 || self.columns.iter().any(|&col| col >= count_cols) {
+            return;
+        }
+
+        self.aggregates.retain(|&(col, _)| col < count_cols);
+
+        let header = table.count_header_groups() + usize::from(table.has_header());
+        let start = std::cmp::min(header, count_rows);
+
+        let records = table.get_records();
+        let body = (start..count_rows)
+            .map(|row| {
+                (0..count_cols)
+                    .map(|col| records.get_text((row, col)).to_owned())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut index = HashMap::new();
+        for (i, row) in body.iter().enumerate() {
+            let key = self
+                .columns
+                .iter()
+                .map(|&col| &row[col])
+                .collect::<Vec<_>>();
+            let group = *index.entry(key).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+
+            groups[group].push(i);
+        }
+
+        // each row is kept along with an original row it's taken from,
+        // so the settings of its cells can be moved too.
+        let mut rows = Vec::new();
+        let mut sections = Vec::new();
+        for group in &groups {
+            let group_rows = group.iter().map(|&i| &body[i]).collect::<Vec<_>>();
+            let values = self
+                .columns
+                .iter()
+                .map(|&col| group_rows[0][col].as_str())
+                .collect::<Vec<_>>();
+
+            let mut header = vec![String::new(); count_cols];
+            header[0] = (self.header)(&values);
+            sections.push(start + rows.len());
+            rows.push((header, None));
+
+            rows.extend(group.iter().map(|&i| (body[i].clone(), Some(start + i))));
+
+            if self.subtotals {
+                let row = self.create_aggregate_row(&self.labels.0, &group_rows, count_cols);
+                rows.push((row, None));
+            }
+        }
+
+        if self.total {
+            let all = body.iter().collect::<Vec<_>>();
+            let row = self.create_aggregate_row(&self.labels.1, &all, count_cols);
+            rows.push((row, None));
+        }
+
+        let new_count_rows = start + rows.len();
+        let records = table.get_records_mut();
+        for row in (new_count_rows..count_rows).rev() {
+            records.remove_row(row);
+        }
+        for _ in count_rows..new_count_rows {
+            records.push_row();
+        }
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        for (i, (row, _)) in rows.iter().enumerate() {
+            for (col, text) in row.iter().enumerate() {
+                table
+                    .get_records_mut()
+                    .set((start + i, col), text.clone(), &width_fn);
+            }
+        }
+
+        let origins = rows.iter().map(|(_, origin)| *origin).collect::<Vec<_>>();
+        move_cell_settings(
+            table.get_config_mut(),
+            (count_rows, count_cols),
+            start,
+            &origins,
+        );
+
+        for row in sections {
+            table.get_config_mut().set_column_span((row, 0), count_cols);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Moves settings of cells to the rows they were moved to, starting from a given row.
+///
+/// Rows which have no origin get settings of their columns,
+/// and spans are removed from all of the rows.
+fn move_cell_settings(
+    cfg: &mut GridConfig,
+    shape: (usize, usize),
+    start: usize,
+    origins: &[Option<usize>],
+) {
+    let spans = cfg
+        .iter_column_spans(shape)
+        .chain(cfg.iter_row_spans(shape))
+        .map(|(pos, _)| pos)
+        .filter(|&(row, _)| row >= start)
+        .collect::<Vec<_>>();
+    for pos in spans {
+        cfg.set_column_span(pos, 1);
+        cfg.set_row_span(pos, 1);
+    }
+
+    let original = cfg.clone();
+    for (i, origin) in origins.iter().enumerate() {
+        for col in 0..shape.1 {
+            let from = match origin {
+                Some(row) => Entity::Cell(*row, col),
+                None => Entity::Column(col),
+            };
+            let to = Entity::Cell(start + i, col);
+
+            cfg.set_padding(to, *original.get_padding(from));
+            cfg.set_formatting(to, *original.get_formatting(from));
+            cfg.set_alignment_horizontal(to, *original.get_alignment_horizontal(from));
+            cfg.set_alignment_vertical(to, *original.get_alignment_vertical(from));
+
+            #[cfg(feature = "color")]
+            {
+                let color = original.get_padding_color(from);
+                let color = papergrid::Sides::new(
+                    own_color(&color.left),
+                    own_color(&color.right),
+                    own_color(&color.top),
+                    own_color(&color.bottom),
+                );
+                cfg.set_padding_color(to, color);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "color")]
+fn own_color(color: &papergrid::AnsiColor<'_>) -> papergrid::AnsiColor<'static> {
+    papergrid::AnsiColor::new(
+        color.get_prefix().to_owned().into(),
+        color.get_suffix().to_owned().into(),
+    )
+}
+
+/// An aggregation of a column used by [`GroupBy`] and [`Pivot`].
+///
+/// Not numeric cells are ignored by all aggregations
+/// but [`Aggregate::Count`], [`Aggregate::First`] and [`Aggregate::Last`].
+///
+/// [`Pivot`]: crate::builder::Pivot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregate {
+    /// A sum of numbers.
+    Sum,
+    /// A count of rows.
+    Count,
+    /// A minimum number.
+    Min,
+    /// A maximum number.
+    Max,
+    /// An arithmetic mean of numbers.
+    Mean,
+    /// A first value as it is.
+    First,
+    /// A last value as it is.
+    Last,
+}
+
+impl Aggregate {
+    /// Calculates an aggregation of values.
+    ///
+    /// An empty string is returned if there's no numbers.
+    ///
+    /// ```
+    /// use tabled::group_by::Aggregate;
+    ///
+    /// assert_eq!(Aggregate::Sum.calculate(["1", "2.5", "N/A"]), "3.5");
+    /// assert_eq!(Aggregate::Count.calculate(["1", "2.5", "N/A"]), "3");
+    /// assert_eq!(Aggregate::Mean.calculate(["1", "2"]), "1.5");
+    /// assert_eq!(Aggregate::Max.calculate(["N/A"]), "");
+    /// assert_eq!(Aggregate::Last.calculate(["1", "N/A"]), "N/A");
+    /// ```
+    pub fn calculate<'a, I>(&self, values: I) -> String
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut values = values.into_iter();
+        match self {
+            Self::Count => return values.count().to_string(),
+            Self::First => return values.next().unwrap_or_default().to_owned(),
+            Self::Last => return values.last().unwrap_or_default().to_owned(),
+            _ => {}
+        }
+
+        let numbers = values
+            .filter_map(|text| text.trim().parse::<f64>().ok())
+            .filter(|value| !value.is_nan())
+            .collect::<Vec<_>>();
+        if numbers.is_empty() {
+            return String::new();
+        }
+
+        let value = match self {
+            Self::Sum => numbers.iter().sum(),
+            Self::Min => numbers.iter().copied().fold(f64::MAX, f64::min),
+            Self::Max => numbers.iter().copied().fold(f64::MIN, f64::max),
+            Self::Mean => numbers.iter().sum::<f64>() / numbers.len() as f64,
+            Self::Count | Self::First | Self::Last => unreachable!(),
+        };
+
+        format_number(value)
+    }
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
@@ -1,8 +1,8 @@
 //! The module contains [`Height`] structure which is responsible for a table and cell height.
 
-use papergrid::{height::HeightEstimator, records::Records, Estimate, GridConfig};
+use papergrid::{height::HeightEstimator, records::Records, Estimate, GridConfig, Position};
 
-use crate::measurement::Measurement;
+use crate::{measurement::Measurement, Table};
 
 mod cell_height_increase;
 mod cell_height_limit;
@@ -262,3 +262,22 @@ where
 
     (total, ctrl.into())
 }
+
+/// Returns a height of a cell content, considering its row span.
+pub(crate) fn get_cell_height<R>(table: &Table<R>, heights: &[usize], pos: Position) -> usize
+where
+    R: Records,
+{
+    let cfg = table.get_config();
+    let shape = table.shape();
+    let span = cfg.get_row_span(pos, shape).unwrap_or(1);
+    let end = std::cmp::min(pos.0 + span, shape.0);
+
+    let height = heights[pos.0..end].iter().sum::<usize>()
+        + (pos.0 + 1..end)
+            .filter(|&i| cfg.has_horizontal(i, shape.0))
+            .count();
+
+    let padding = cfg.get_padding(pos.into());
+    height.saturating_sub(padding.top.size + padding.bottom.size)
+}
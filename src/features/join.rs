@@ -0,0 +1,301 @@
+//! This module contains a [`Join`] structure which combines 2 [`Table`]s by key columns,
+//! like SQL's join does.
+//!
+//! [`Table`]: crate::Table
+
+use std::collections::{HashMap, HashSet};
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{locator::Locator, Table, TableOption};
+
+/// [`Join`] combines rows of 2 tables which have the same values in key columns.
+///
+/// A first row of both tables is considered a header,
+/// so key columns are usually located by [`ByColumnName`].
+/// A resulting table has all columns of a left table
+/// followed by not key columns of a right one.
+///
+/// Rows go in order of a left table,
+/// and in case of [`Join::outer`] they are followed by not matched rows of a right table.
+///
+/// If a key column can't be found in any of the tables nothing is changed.
+///
+/// ```
+/// use tabled::{locator::ByColumnName, Join, Style, Table};
+///
+/// let users = [(1, "Alice"), (2, "Bob"), (3, "Eve")];
+/// let orders = [(1, "book"), (1, "pen"), (3, "cup")];
+///
+/// let mut users = Table::builder(users);
+/// users.set_columns(["id", "name"]);
+///
+/// let mut orders = Table::builder(orders);
+/// orders.set_columns(["id", "item"]);
+///
+/// let mut table = users.build();
+/// table
+///     .with(Join::left(orders.build(), [ByColumnName::new("id")]).default_cell("-"))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " id | name  | item \n",
+///         "----+-------+------\n",
+///         " 1  | Alice | book \n",
+///         " 1  | Alice | pen  \n",
+///         " 2  | Bob   | -    \n",
+///         " 3  | Eve   | cup  ",
+///     )
+/// );
+/// ```
+///
+/// [`ByColumnName`]: crate::locator::ByColumnName
+#[derive(Debug)]
+pub struct Join<T, L> {
+    table: Table<T>,
+    keys: Vec<L>,
+    kind: JoinKind,
+    default_cell: String,
+    suffixes: (String, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinKind {
+    Inner,
+    Left,
+    Outer,
+}
+
+impl<T, L> Join<T, L> {
+    fn new<K>(table: Table<T>, keys: K, kind: JoinKind) -> Self
+    where
+        K: IntoIterator<Item = L>,
+    {
+        Self {
+            table,
+            keys: keys.into_iter().collect(),
+            kind,
+            default_cell: String::new(),
+            suffixes: (String::from("_x"), String::from("_y")),
+        }
+    }
+
+    /// Keeps only rows which have a match in both tables.
+    pub fn inner<K>(table: Table<T>, keys: K) -> Self
+    where
+        K: IntoIterator<Item = L>,
+    {
+        Self::new(table, keys, JoinKind::Inner)
+    }
+
+    /// Keeps all rows of a left table, even if they have no match in a right one.
+    pub fn left<K>(table: Table<T>, keys: K) -> Self
+    where
+        K: IntoIterator<Item = L>,
+    {
+        Self::new(table, keys, JoinKind::Left)
+    }
+
+    /// Keeps all rows of both tables.
+    pub fn outer<K>(table: Table<T>, keys: K) -> Self
+    where
+        K: IntoIterator<Item = L>,
+    {
+        Self::new(table, keys, JoinKind::Outer)
+    }
+
+    /// Sets a cell's content for cases where a row has no match.
+    pub fn default_cell(mut self, cell: impl Into<String>) -> Self {
+        self.default_cell = cell.into();
+        self
+    }
+
+    /// Sets suffixes which are added to clashing column names of left and right tables.
+    ///
+    /// By default they are `_x` and `_y`.
+    pub fn suffixes(mut self, left: impl Into<String>, right: impl Into<String>) -> Self {
+        self.suffixes = (left.into(), right.into());
+        self
+    }
+}
+
+impl<T, L, R> TableOption<R> for Join<T, L>
+where
+    R: Records + Resizable + RecordsMut<String>,
+    T: Records,
+    L: Locator<Coordinate = usize>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.shape().0 == 0 || self.table.shape().0 == 0 || self.keys.is_empty() {
+            return;
+        }
+
+        let mut left_keys = Vec::with_capacity(self.keys.len());
+        let mut right_keys = Vec::with_capacity(self.keys.len());
+        for key in &mut self.keys {
+            let left = key.locate(table.get_records()).into_iter().next();
+            let right = key.locate(self.table.get_records()).into_iter().next();
+            match (left, right) {
+                (Some(left), Some(right)) => {
+                    left_keys.push(left);
+                    right_keys.push(right);
+                }
+                _ => return,
+            }
+        }
+
+        let left = collect_records(table.get_records());
+        let right = collect_records(self.table.get_records());
+
+        let rows = self.join(&left, &right, &left_keys, &right_keys);
+        set_records(table, rows);
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+impl<T, L> Join<T, L> {
+    fn join(
+        &self,
+        left: &[Vec<String>],
+        right: &[Vec<String>],
+        left_keys: &[usize],
+        right_keys: &[usize],
+    ) -> Vec<Vec<String>> {
+        let count_left_columns = left[0].len();
+        let right_columns = (0..right[0].len())
+            .filter(|col| !right_keys.contains(col))
+            .collect::<Vec<_>>();
+
+        let mut rows = vec![self.create_header(&left[0], &right[0], &right_columns)];
+
+        let mut index: HashMap<Vec<&str>, Vec<usize>> = HashMap::new();
+        for (i, row) in right.iter().enumerate().skip(1) {
+            index.entry(get_key(row, right_keys)).or_default().push(i);
+        }
+
+        let mut matched = HashSet::new();
+        for row in &left[1..] {
+            let matches = index.get(&get_key(row, left_keys));
+            match matches {
+                Some(matches) => {
+                    for &i in matches {
+                        let mut new_row = row.clone();
+                        new_row.extend(right_columns.iter().map(|&col| right[i][col].clone()));
+                        rows.push(new_row);
+                    }
+
+                    matched.extend(matches.iter().copied());
+                }
+                None if self.kind != JoinKind::Inner => {
+                    let mut new_row = row.clone();
+                    new_row.resize(
+                        count_left_columns + right_columns.len(),
+                        self.default_cell.clone(),
+                    );
+                    rows.push(new_row);
+                }
+                None => {}
+            }
+        }
+
+        if self.kind == JoinKind::Outer {
+            for (i, row) in right.iter().enumerate().skip(1) {
+                if matched.contains(&i) {
+                    continue;
+                }
+
+                let mut new_row = vec![self.default_cell.clone(); count_left_columns];
+                for (&left_col, &right_col) in left_keys.iter().zip(right_keys) {
+                    new_row[left_col] = row[right_col].clone();
+                }
+
+                new_row.extend(right_columns.iter().map(|&col| row[col].clone()));
+                rows.push(new_row);
+            }
+        }
+
+        rows
+    }
+
+    fn create_header(
+        &self,
+        left: &[String],
+        right: &[String],
+        right_columns: &[usize],
+    ) -> Vec<String> {
+        let left_names = left.iter().map(String::as_str).collect::<HashSet<_>>();
+        let right_names = right_columns
+            .iter()
+            .map(|&col| right[col].as_str())
+            .collect::<HashSet<_>>();
+
+        let mut header = left
+            .iter()
+            .map(|name| match right_names.contains(name.as_str()) {
+                true => format!("{}{}", name, self.suffixes.0),
+                false => name.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        header.extend(right_columns.iter().map(|&col| {
+            let name = &right[col];
+            match left_names.contains(name.as_str()) {
+                true => format!("{}{}", name, self.suffixes.1),
+                false => name.clone(),
+            }
+        }));
+
+        header
+    }
+}
+
+fn get_key<'a>(row: &'a [String], keys: &[usize]) -> Vec<&'a str> {
+    keys.iter().map(|&col| row[col].as_str()).collect()
+}
+
+fn collect_records<R>(records: R) -> Vec<Vec<String>>
+where
+    R: Records,
+{
+    (0..records.count_rows())
+        .map(|row| {
+            (0..records.count_columns())
+                .map(|col| records.get_text((row, col)).to_owned())
+                .collect()
+        })
+        .collect()
+}
+
+fn set_records<R>(table: &mut Table<R>, rows: Vec<Vec<String>>)
+where
+    R: Records + Resizable + RecordsMut<String>,
+{
+    let (count_rows, count_cols) = table.shape();
+    let new_count_rows = rows.len();
+    let new_count_cols = rows.first().map_or(0, Vec::len);
+
+    let records = table.get_records_mut();
+    for row in (new_count_rows..count_rows).rev() {
+        records.remove_row(row);
+    }
+    for _ in count_rows..new_count_rows {
+        records.push_row();
+    }
+    for _ in count_cols..new_count_cols {
+        records.push_column();
+    }
+
+    let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+    for (i, row) in rows.into_iter().enumerate() {
+        for (col, text) in row.into_iter().enumerate() {
+            table.get_records_mut().set((i, col), text, &ctrl);
+        }
+    }
+}
@@ -105,7 +105,7 @@ where
         let (from, to) = bounds_to_usize(
             self.get_range().start_bound(),
             self.get_range().end_bound(),
-            records.count_columns(),
+            records.count_rows(),
         );
 
         from..to
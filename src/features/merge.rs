@@ -2,10 +2,20 @@
 //!
 //! [`Span`]: crate::Span
 
-use crate::{papergrid::records::Records, Table, TableOption};
+use std::ops::{Range, RangeFull};
+
+use crate::{
+    locator::Locator,
+    object::{Columns, Rows},
+    papergrid::records::Records,
+    Table, TableOption,
+};
 
 /// Merge to combine duplicates together, using [`Span`].
 ///
+/// A merge which can be limited to some columns or rows,
+/// use a custom equality or be bounded by a parent column is [`MergeDuplicates`].
+///
 /// [`Span`]: crate::Span
 #[derive(Debug)]
 pub struct Merge;
@@ -209,3 +219,237 @@ where
         table.destroy_height_cache();
     }
 }
+
+/// A configurable merge of duplicates, using [`Span`].
+///
+/// It can be limited to some columns (or rows in case of a horizontal merge),
+/// compare cells by a custom equality,
+/// and merge cells only inside groups of parent columns (or rows),
+/// so a child column is not merged across boundaries of its parent.
+///
+/// Cells which are already spanned are not merged.
+///
+/// ```
+/// use tabled::{merge::MergeDuplicates, object::Columns, Style, Table};
+///
+/// let data = [
+///     ("EU", "2021", "Berlin"),
+///     ("EU", "2022", "berlin"),
+///     ("US", "2022", "Boston"),
+/// ];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(MergeDuplicates::vertical().lines(Columns::first()))
+///     .with(
+///         MergeDuplicates::vertical()
+///             .lines(Columns::new(1..))
+///             .within(Columns::first())
+///             .ignore_case(),
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "┌──────┬──────┬────────┐\n",
+///         "│ &str │ &str │ &str   │\n",
+///         "├──────┼──────┼────────┤\n",
+///         "│ EU   │ 2021 │ Berlin │\n",
+///         "├      ┼──────┼        ┤\n",
+///         "│      │ 2022 │        │\n",
+///         "├──────┼──────┼────────┤\n",
+///         "│ US   │ 2022 │ Boston │\n",
+///         "└──────┴──────┴────────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Span`]: crate::Span
+#[derive(Debug, Clone)]
+pub struct MergeDuplicates<L, P, F> {
+    vertical: bool,
+    lines: L,
+    parents: P,
+    compare: F,
+}
+
+type Compare = fn(&str, &str) -> bool;
+
+impl MergeDuplicates<Columns<RangeFull>, Columns<Range<usize>>, Compare> {
+    /// Merges duplicates in columns.
+    pub fn vertical() -> Self {
+        Self {
+            vertical: true,
+            lines: Columns::new(..),
+            parents: Columns::new(0..0),
+            compare: |a, b| a == b,
+        }
+    }
+}
+
+impl MergeDuplicates<Rows<RangeFull>, Rows<Range<usize>>, Compare> {
+    /// Merges duplicates in rows.
+    pub fn horizontal() -> Self {
+        Self {
+            vertical: false,
+            lines: Rows::new(..),
+            parents: Rows::new(0..0),
+            compare: |a, b| a == b,
+        }
+    }
+}
+
+impl<L, P, F> MergeDuplicates<L, P, F> {
+    /// Limits a merge to given columns (or rows in case of a horizontal merge).
+    pub fn lines<N>(self, lines: N) -> MergeDuplicates<N, P, F>
+    where
+        N: Locator<Coordinate = usize>,
+    {
+        MergeDuplicates {
+            vertical: self.vertical,
+            lines,
+            parents: self.parents,
+            compare: self.compare,
+        }
+    }
+
+    /// Sets parent columns (or rows in case of a horizontal merge),
+    /// cells are merged only while values of all parents are equal.
+    pub fn within<N>(self, parents: N) -> MergeDuplicates<L, N, F>
+    where
+        N: Locator<Coordinate = usize>,
+    {
+        MergeDuplicates {
+            vertical: self.vertical,
+            lines: self.lines,
+            parents,
+            compare: self.compare,
+        }
+    }
+
+    /// Sets a function which checks whether 2 cells are equal.
+    ///
+    /// It's used for parents as well.
+    pub fn compare<C>(self, compare: C) -> MergeDuplicates<L, P, C>
+    where
+        C: Fn(&str, &str) -> bool,
+    {
+        MergeDuplicates {
+            vertical: self.vertical,
+            lines: self.lines,
+            parents: self.parents,
+            compare,
+        }
+    }
+
+    /// Compares cells by keys extracted from their text.
+    pub fn key<K, T>(self, key: K) -> MergeDuplicates<L, P, impl Fn(&str, &str) -> bool>
+    where
+        K: Fn(&str) -> T,
+        T: PartialEq,
+    {
+        self.compare(move |a, b| key(a) == key(b))
+    }
+
+    /// Compares cells ignoring ASCII case.
+    ///
+    /// It replaces a previously set comparison.
+    pub fn ignore_case(self) -> MergeDuplicates<L, P, Compare> {
+        self.compare(|a, b| a.eq_ignore_ascii_case(b))
+    }
+
+    /// Compares cells ignoring leading and trailing whitespaces.
+    ///
+    /// It replaces a previously set comparison.
+    pub fn trim(self) -> MergeDuplicates<L, P, Compare> {
+        self.compare(|a, b| a.trim() == b.trim())
+    }
+}
+
+impl<L, P, F, R> TableOption<R> for MergeDuplicates<L, P, F>
+where
+    L: Locator<Coordinate = usize>,
+    P: Locator<Coordinate = usize>,
+    F: Fn(&str, &str) -> bool,
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.is_empty() {
+            return;
+        }
+
+        let shape = table.shape();
+        let (count_lines, count_cells) = match self.vertical {
+            true => (shape.1, shape.0),
+            false => (shape.0, shape.1),
+        };
+        let vertical = self.vertical;
+        let pos = |line: usize, i: usize| if vertical { (i, line) } else { (line, i) };
+
+        let lines = self.lines.locate(table.get_records());
+        let parents = self
+            .parents
+            .locate(table.get_records())
+            .into_iter()
+            .filter(|&line| line < count_lines)
+            .collect::<Vec<_>>();
+
+        let mut spans = Vec::new();
+        for line in lines.into_iter().filter(|&line| line < count_lines) {
+            let records = table.get_records();
+            let cfg = table.get_config();
+            let is_mergeable = |i: usize| {
+                let pos = pos(line, i);
+                cfg.is_cell_visible(pos, shape)
+                    && cfg.get_row_span(pos, shape).is_none()
+                    && cfg.get_column_span(pos, shape).is_none()
+            };
+            let is_boundary = |i: usize| {
+                parents.iter().any(|&parent| {
+                    let prev = records.get_text(pos(parent, i - 1));
+                    let next = records.get_text(pos(parent, i));
+                    !(self.compare)(prev, next)
+                })
+            };
+
+            let mut start: Option<usize> = None;
+            for i in 0..=count_cells {
+                if let Some(first) = start {
+                    let is_duplicate = i < count_cells
+                        && is_mergeable(i)
+                        && !is_boundary(i)
+                        && (self.compare)(
+                            records.get_text(pos(line, first)),
+                            records.get_text(pos(line, i)),
+                        );
+                    if is_duplicate {
+                        continue;
+                    }
+
+                    if i - first > 1 {
+                        spans.push((pos(line, first), i - first));
+                    }
+                }
+
+                start = if i < count_cells && is_mergeable(i) {
+                    Some(i)
+                } else {
+                    None
+                };
+            }
+        }
+
+        let cfg = table.get_config_mut();
+        for (pos, span) in spans {
+            if self.vertical {
+                cfg.set_row_span(pos, span);
+            } else {
+                cfg.set_column_span(pos, span);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
@@ -1,12 +1,17 @@
 pub mod alignment;
+pub mod chart;
+pub mod color_depth;
+pub mod conditional;
 pub mod format;
 pub mod formatting;
+pub mod group_by;
 pub mod height;
 #[allow(unreachable_pub)]
 pub mod highlight;
 pub mod locator;
 pub mod measurement;
 pub mod peaker;
+pub mod rich;
 pub mod shadow;
 pub mod style;
 pub mod width;
@@ -21,9 +26,12 @@ pub mod padding_color;
 pub(crate) mod concat;
 pub(crate) mod disable;
 pub(crate) mod extract;
+pub(crate) mod join;
 pub(crate) mod margin;
 pub mod merge;
+pub(crate) mod nested;
 pub(crate) mod padding;
 pub(crate) mod panel;
 pub(crate) mod rotate;
 pub(crate) mod span;
+pub(crate) mod split;
@@ -0,0 +1,511 @@
+//! This module contains a [`Nested`] structure, which places a [`Table`] inside a cell of another table.
+//!
+//! A nested table is laid out to a width of a cell it's put in,
+//! and it may be collapsed so its frame is merged with borders of a parent table.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Cell, Modify, Nested, Style, Table};
+//!
+//! let inner = Table::new([("a", 1), ("b", 2)]).with(Style::modern()).clone();
+//!
+//! let table = Table::new([("letters", "")])
+//!     .with(Style::modern())
+//!     .with(Modify::new(Cell(1, 1)).with(Nested::new(inner).collapse()))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "┌─────────┬────────────┐\n",
+//!         "│ &str    │ &str       │\n",
+//!         "├─────────┼──────┬─────┤\n",
+//!         "│ letters │ &str │ i32 │\n",
+//!         "│         ├──────┼─────┤\n",
+//!         "│         │ a    │ 1   │\n",
+//!         "│         ├──────┼─────┤\n",
+//!         "│         │ b    │ 2   │\n",
+//!         "└─────────┴──────┴─────┘",
+//!     )
+//! );
+//! ```
+
+use std::borrow::Cow;
+
+use papergrid::{
+    records::{cell_info::CellInfo, vec_records::VecRecords, Records, RecordsMut},
+    util::{count_lines, cut_str, spplit_str_at, string_width, string_width_multiline},
+    width::CfgWidthFunction,
+    Entity, Margin, Offset, Padding, Position,
+};
+
+use crate::{
+    height::{get_cell_height, get_table_total_height2},
+    width::{get_cell_width, get_table_widths},
+    CellOption, Height, Table, Width,
+};
+
+/// [`Nested`] sets a [`Table`] as a content of a cell.
+///
+/// The table is increased to a width of a cell,
+/// so it must be set after all options which change widths.
+/// As a width of a column depends on its cells,
+/// a widest nested table of a column is better be set first.
+///
+/// By default a nested table is rendered as is, including its frame and margin,
+/// and it's positioned by a vertical alignment of a cell if a row is higher.
+/// A [`Nested::collapse`]d table fills a cell entirely and shares borders with a parent table.
+///
+/// ```
+/// use tabled::{object::Cell, Modify, Nested, Table};
+///
+/// let inner = Table::new([1, 2]);
+///
+/// let table = Table::new([("text", "a wide cell"), ("nested table", "")])
+///     .with(Modify::new(Cell(2, 1)).with(Nested::new(inner)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+--------------+-------------+\n",
+///         "| &str         | &str        |\n",
+///         "+--------------+-------------+\n",
+///         "| text         | a wide cell |\n",
+///         "+--------------+-------------+\n",
+///         "| nested table | +---------+ |\n",
+///         "|              | | i32     | |\n",
+///         "|              | +---------+ |\n",
+///         "|              | | 1       | |\n",
+///         "|              | +---------+ |\n",
+///         "|              | | 2       | |\n",
+///         "|              | +---------+ |\n",
+///         "+--------------+-------------+",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Nested<R = VecRecords<CellInfo<'static>>> {
+    table: Table<R>,
+    collapse: bool,
+}
+
+impl<R> Nested<R> {
+    /// Creates a [`Nested`] option from a table.
+    pub fn new(table: Table<R>) -> Self {
+        Self {
+            table,
+            collapse: false,
+        }
+    }
+
+    /// Merges a frame of a nested table with borders of a cell.
+    ///
+    /// A frame and a margin of a nested table are removed, as well as a padding of a cell.
+    /// Intersections of the nested table are copied to borders of the cell,
+    /// so it's expected that both tables use a similar [`Style`].
+    ///
+    /// [`Style`]: crate::Style
+    pub fn collapse(mut self) -> Self {
+        self.collapse = true;
+        self
+    }
+}
+
+impl<R, R2> CellOption<R> for Nested<R2>
+where
+    R: Records + RecordsMut<String>,
+    R2: Records + RecordsMut<String> + Clone,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let cfg = table.get_config();
+            if cfg.is_cell_covered_by_column_span(pos, (count_rows, count_cols))
+                || cfg.is_cell_covered_by_row_span(pos, (count_rows, count_cols))
+            {
+                continue;
+            }
+
+            self.set_table(table, pos);
+        }
+    }
+}
+
+impl<R2> Nested<R2>
+where
+    R2: Records + RecordsMut<String> + Clone,
+{
+    fn set_table<R>(&self, table: &mut Table<R>, pos: Position)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        let mut inner = self.table.clone();
+        let frame = if self.collapse {
+            inner.get_config_mut().set_margin(Margin::default());
+            table
+                .get_config_mut()
+                .set_padding(pos.into(), Padding::default());
+
+            Frame::new(&inner)
+        } else {
+            Frame::default()
+        };
+
+        let (text, _) = render_table(&inner, &frame);
+        let layout = CellLayout::new(table, pos, &text);
+
+        inner.with(Width::increase(layout.width + frame.count_vertical()));
+        if self.collapse {
+            inner.with(Height::increase(layout.height + frame.count_horizontal()));
+        }
+
+        let (text, chars) = render_table(&inner, &frame);
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        table.get_records_mut().set(pos, text, &width_fn);
+
+        if self.collapse {
+            set_junctions(table, &inner, &chars, &layout, pos);
+        }
+    }
+}
+
+/// Sides of a nested table which are merged with borders of a cell.
+#[derive(Debug, Default)]
+struct Frame {
+    top: bool,
+    bottom: bool,
+    left: bool,
+    right: bool,
+}
+
+impl Frame {
+    fn new<R>(table: &Table<R>) -> Self
+    where
+        R: Records,
+    {
+        let cfg = table.get_config();
+        let (count_rows, count_cols) = table.shape();
+        if count_rows == 0 || count_cols == 0 {
+            return Self::default();
+        }
+
+        Self {
+            top: cfg.has_horizontal(0, count_rows),
+            bottom: cfg.has_horizontal(count_rows, count_rows),
+            left: cfg.has_vertical(0, count_cols),
+            right: cfg.has_vertical(count_cols, count_cols),
+        }
+    }
+
+    fn count_vertical(&self) -> usize {
+        self.left as usize + self.right as usize
+    }
+
+    fn count_horizontal(&self) -> usize {
+        self.top as usize + self.bottom as usize
+    }
+}
+
+/// Sizes of a parent table used to fit a nested table into a cell.
+struct CellLayout {
+    widths: Vec<usize>,
+    heights: Vec<usize>,
+    width: usize,
+    height: usize,
+}
+
+impl CellLayout {
+    fn new<R>(table: &mut Table<R>, pos: Position, text: &str) -> Self
+    where
+        R: Records + RecordsMut<String>,
+    {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        table.get_records_mut().set(pos, text.to_owned(), &width_fn);
+
+        // sizes set by other options (like `Width::increase`) are kept if a table fits a cell
+        let mut widths = match table.get_width_cache() {
+            Some(widths) => widths.to_vec(),
+            None => get_table_widths(table.get_records(), table.get_config()),
+        };
+        let mut width = get_cell_width(table, &widths, pos);
+        if width < string_width_multiline(text) {
+            table.destroy_width_cache();
+            widths = get_table_widths(table.get_records(), table.get_config());
+            width = get_cell_width(table, &widths, pos);
+        }
+
+        let mut heights = match table.get_height_cache() {
+            Some(heights) => heights.to_vec(),
+            None => get_table_total_height2(table.get_records(), table.get_config()).1,
+        };
+        let mut height = get_cell_height(table, &heights, pos);
+        if height < count_lines(text) {
+            table.destroy_height_cache();
+            heights = get_table_total_height2(table.get_records(), table.get_config()).1;
+            height = get_cell_height(table, &heights, pos);
+        }
+
+        Self {
+            widths,
+            heights,
+            width,
+            height,
+        }
+    }
+}
+
+/// Renders a table without a frame and returns chars of the frame.
+fn render_table<R>(table: &Table<R>, frame: &Frame) -> (String, FrameChars)
+where
+    R: Records,
+{
+    let text = table.to_string();
+
+    let mut chars = FrameChars::default();
+    let mut lines = text.lines().collect::<Vec<_>>();
+    if frame.bottom {
+        if let Some(line) = lines.pop() {
+            chars.bottom = get_split_line_chars(line, frame);
+        }
+    }
+
+    if frame.top && !lines.is_empty() {
+        chars.top = get_split_line_chars(lines.remove(0), frame);
+    }
+
+    let text = lines
+        .into_iter()
+        .map(|line| {
+            let plain = strip_ansi(line);
+            if frame.left {
+                chars.left.extend(plain.chars().next());
+            }
+
+            if frame.right {
+                chars.right.extend(plain.chars().last());
+            }
+
+            let line = match frame.left {
+                true => spplit_str_at(line, 1).1,
+                false => Cow::Borrowed(line),
+            };
+
+            match frame.right {
+                true => cut_str(&line, string_width(&line).saturating_sub(1)).into_owned(),
+                false => line.into_owned(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    (text, chars)
+}
+
+/// Chars of a frame of a nested table, excluding corners.
+#[derive(Debug, Default)]
+struct FrameChars {
+    top: Vec<char>,
+    bottom: Vec<char>,
+    left: Vec<char>,
+    right: Vec<char>,
+}
+
+fn get_split_line_chars(line: &str, frame: &Frame) -> Vec<char> {
+    let mut chars = strip_ansi(line).chars().collect::<Vec<_>>();
+    if frame.right {
+        chars.pop();
+    }
+
+    if frame.left && !chars.is_empty() {
+        chars.remove(0);
+    }
+
+    chars
+}
+
+#[cfg(feature = "color")]
+fn strip_ansi(text: &str) -> Cow<'_, str> {
+    ansi_str::AnsiStr::ansi_strip(text)
+}
+
+#[cfg(not(feature = "color"))]
+fn strip_ansi(text: &str) -> Cow<'_, str> {
+    Cow::Borrowed(text)
+}
+
+/// Copies junctions of a frame of a nested table onto borders of a cell it's put in.
+///
+/// A junction is any char of a frame which differs from a border of an adjacent cell,
+/// so intersections and chars set by other options (like another nested table) are copied.
+fn set_junctions<R, R2>(
+    table: &mut Table<R>,
+    inner: &Table<R2>,
+    chars: &FrameChars,
+    layout: &CellLayout,
+    pos: Position,
+) where
+    R: Records,
+    R2: Records,
+{
+    let cfg = inner.get_config();
+    let (count_rows, count_cols) = inner.shape();
+    if count_rows == 0 || count_cols == 0 {
+        return;
+    }
+
+    let widths = match inner.get_width_cache() {
+        Some(widths) => widths.to_vec(),
+        None => get_table_widths(inner.get_records(), cfg),
+    };
+    let heights = match inner.get_height_cache() {
+        Some(heights) => heights.to_vec(),
+        None => get_table_total_height2(inner.get_records(), cfg).1,
+    };
+
+    let outer_shape = table.shape();
+    let row_span = table
+        .get_config()
+        .get_row_span(pos, outer_shape)
+        .unwrap_or(1);
+    let col_span = table
+        .get_config()
+        .get_column_span(pos, outer_shape)
+        .unwrap_or(1);
+    let bottom_line = pos.0 + row_span;
+    let right_line = pos.1 + col_span;
+
+    let has_vertical = |col| cfg.has_vertical(col, count_cols);
+    let has_horizontal = |row| cfg.has_horizontal(row, count_rows);
+
+    let top = find_junctions(&chars.top, &widths, has_vertical, |col| {
+        cfg.get_horizontal((0, col), count_rows).copied()
+    });
+    for (x, c) in top {
+        override_horizontal(table, &layout.widths, pos, pos.0, x, c);
+    }
+
+    let bottom = find_junctions(&chars.bottom, &widths, has_vertical, |col| {
+        cfg.get_horizontal((count_rows, col), count_rows).copied()
+    });
+    for (x, c) in bottom {
+        override_horizontal(table, &layout.widths, pos, bottom_line, x, c);
+    }
+
+    let left = find_junctions(&chars.left, &heights, has_horizontal, |row| {
+        cfg.get_vertical((row, 0), count_cols).copied()
+    });
+    for (y, c) in left {
+        override_vertical(table, &layout.heights, pos, pos.1, y, c);
+    }
+
+    let right = find_junctions(&chars.right, &heights, has_horizontal, |row| {
+        cfg.get_vertical((row, count_cols), count_cols).copied()
+    });
+    for (y, c) in right {
+        override_vertical(table, &layout.heights, pos, right_line, y, c);
+    }
+}
+
+/// Returns offsets of chars of a side of a frame which differ from a border of an adjacent cell.
+fn find_junctions<L, B>(
+    chars: &[char],
+    sizes: &[usize],
+    has_line: L,
+    border: B,
+) -> Vec<(usize, char)>
+where
+    L: Fn(usize) -> bool,
+    B: Fn(usize) -> Option<char>,
+{
+    let mut junctions = Vec::new();
+    let mut offset = 0;
+    for (i, size) in sizes.iter().enumerate() {
+        let has_split = i + 1 < sizes.len() && has_line(i + 1);
+        let end = std::cmp::min(offset + size + has_split as usize, chars.len());
+
+        let border = border(i);
+        for (j, &c) in chars.iter().enumerate().take(end).skip(offset) {
+            if matches!(border, Some(border) if border != c) {
+                junctions.push((j, c));
+            }
+        }
+
+        offset = end;
+    }
+
+    junctions
+}
+
+/// Overrides a char of a horizontal line over a cell, considering its column span.
+///
+/// A char which hits an intersection inside a span is left as is.
+fn override_horizontal<R>(
+    table: &mut Table<R>,
+    widths: &[usize],
+    pos: Position,
+    line: usize,
+    mut offset: usize,
+    c: char,
+) where
+    R: Records,
+{
+    let shape = table.shape();
+    let cfg = table.get_config_mut();
+    let span = cfg.get_column_span(pos, shape).unwrap_or(1);
+    let end = std::cmp::min(pos.1 + span, shape.1);
+
+    for (col, &width) in widths.iter().enumerate().take(end).skip(pos.1) {
+        if offset < width {
+            cfg.override_horizontal_border((line, col), c, Offset::Begin(offset));
+            return;
+        }
+
+        offset -= width;
+
+        if col + 1 < end && cfg.has_vertical(col + 1, shape.1) {
+            if offset == 0 {
+                return;
+            }
+
+            offset -= 1;
+        }
+    }
+}
+
+/// Overrides a char of a vertical line along a cell, considering its row span.
+///
+/// A char which hits an intersection inside a span is left as is.
+fn override_vertical<R>(
+    table: &mut Table<R>,
+    heights: &[usize],
+    pos: Position,
+    line: usize,
+    mut offset: usize,
+    c: char,
+) where
+    R: Records,
+{
+    let shape = table.shape();
+    let cfg = table.get_config_mut();
+    let span = cfg.get_row_span(pos, shape).unwrap_or(1);
+    let end = std::cmp::min(pos.0 + span, shape.0);
+
+    for (row, &height) in heights.iter().enumerate().take(end).skip(pos.0) {
+        if offset < height {
+            cfg.override_vertical_border((row, line), c, Offset::Begin(offset));
+            return;
+        }
+
+        offset -= height;
+
+        if row + 1 < end && cfg.has_horizontal(row + 1, shape.0) {
+            if offset == 0 {
+                return;
+            }
+
+            offset -= 1;
+        }
+    }
+}
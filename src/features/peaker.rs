@@ -61,8 +61,10 @@ impl Peaker for PriorityMax {
         Self
     }
 
-    fn peak(&mut self, _: &[usize], widths: &[usize]) -> Option<usize> {
-        let col = (0..widths.len()).max_by_key(|&i| widths[i]).unwrap();
+    fn peak(&mut self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+        let col = (0..widths.len())
+            .filter(|&i| min_widths.is_empty() || widths[i] > min_widths[i])
+            .max_by_key(|&i| widths[i])?;
         if widths[col] == 0 {
             None
         } else {
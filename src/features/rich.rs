@@ -0,0 +1,776 @@
+//! This module contains a [`RichText`] cell, which keeps a styled text as a list of [`Span`]s
+//! instead of a string with embedded ANSI sequences.
+//!
+//! It can be used with [`VecRecords`] as any other cell,
+//! and its styles survive [`Width::wrap`], [`Width::truncate`], [`Format`] and [`Rotate`]
+//! without re-parsing of ANSI sequences.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     builder::Builder,
+//!     papergrid::records::vec_records::VecRecords,
+//!     rich::{RichText, TextColor, TextStyle},
+//! };
+//!
+//! let mut status = RichText::default();
+//! status.push("OK", TextStyle::new().fg(TextColor::Green).bold());
+//!
+//! let records = VecRecords::from(vec![
+//!     vec![RichText::from("name"), RichText::from("status")],
+//!     vec![RichText::from("server-1"), status],
+//! ]);
+//!
+//! let table = Builder::custom(records).build().to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+----------+--------+\n",
+//!         "| name     | status |\n",
+//!         "+----------+--------+\n",
+//!         "| server-1 | \u{1b}[1;32mOK\u{1b}[0m     |\n",
+//!         "+----------+--------+",
+//!     ),
+//! );
+//! ```
+//!
+//! [`VecRecords`]: papergrid::records::vec_records::VecRecords
+//! [`Width::wrap`]: crate::Width::wrap
+//! [`Width::truncate`]: crate::Width::truncate
+//! [`Format`]: crate::format::Format
+//! [`Rotate`]: crate::Rotate
+
+use std::{fmt, ops::Range};
+
+use papergrid::{
+    records::vec_records::{Cell, CellMut},
+    width::WidthFunc,
+};
+
+#[cfg(feature = "color")]
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+#[cfg(feature = "color")]
+use crate::{CellOption, Table};
+
+/// A color of a text used in [`TextStyle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextColor {
+    /// A black color.
+    Black,
+    /// A red color.
+    Red,
+    /// A green color.
+    Green,
+    /// A yellow color.
+    Yellow,
+    /// A blue color.
+    Blue,
+    /// A magenta color.
+    Magenta,
+    /// A cyan color.
+    Cyan,
+    /// A white color.
+    White,
+    /// A bright black (gray) color.
+    BrightBlack,
+    /// A bright red color.
+    BrightRed,
+    /// A bright green color.
+    BrightGreen,
+    /// A bright yellow color.
+    BrightYellow,
+    /// A bright blue color.
+    BrightBlue,
+    /// A bright magenta color.
+    BrightMagenta,
+    /// A bright cyan color.
+    BrightCyan,
+    /// A bright white color.
+    BrightWhite,
+    /// A color from a 256 colors palette.
+    Fixed(u8),
+    /// A 24-bit color.
+    Rgb(u8, u8, u8),
+}
+
+impl TextColor {
+    /// Returns a RGB representation of a color.
+    ///
+    /// The named colors use the xterm default palette.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Fixed(n) => fixed_to_rgb(n),
+            named => fixed_to_rgb(named.named_index().unwrap_or_default()),
+        }
+    }
+
+    pub(crate) fn named_index(self) -> Option<u8> {
+        let index = match self {
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::BrightBlack => 8,
+            Self::BrightRed => 9,
+            Self::BrightGreen => 10,
+            Self::BrightYellow => 11,
+            Self::BrightBlue => 12,
+            Self::BrightMagenta => 13,
+            Self::BrightCyan => 14,
+            Self::BrightWhite => 15,
+            Self::Fixed(_) | Self::Rgb(..) => return None,
+        };
+
+        Some(index)
+    }
+
+    pub(crate) fn fmt_ansi(self, f: &mut String, is_background: bool) {
+        let shift = if is_background { 10 } else { 0 };
+        match self {
+            Self::Fixed(n) => f.push_str(&format!("{};5;{}", 38 + shift, n)),
+            Self::Rgb(r, g, b) => f.push_str(&format!("{};2;{};{};{}", 38 + shift, r, g, b)),
+            named => {
+                let index = named.named_index().unwrap_or_default();
+                let code = if index < 8 {
+                    30 + index
+                } else {
+                    90 + index - 8
+                };
+                f.push_str(&(code + shift).to_string());
+            }
+        }
+    }
+}
+
+/// A set of attributes of a text used by [`Span`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextStyle {
+    fg: Option<TextColor>,
+    bg: Option<TextColor>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+}
+
+impl TextStyle {
+    /// Creates a style with no attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a foreground color.
+    pub fn fg(mut self, color: TextColor) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets a background color.
+    pub fn bg(mut self, color: TextColor) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Makes a text bold.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Makes a text dimmed.
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Makes a text italic.
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Makes a text underlined.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Makes a text crossed out.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Returns a foreground color.
+    pub fn get_fg(&self) -> Option<TextColor> {
+        self.fg
+    }
+
+    /// Returns a background color.
+    pub fn get_bg(&self) -> Option<TextColor> {
+        self.bg
+    }
+
+    /// Checks whether the style has no attributes.
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns an ANSI SGR sequence of the style, or an empty string if the style is plain.
+    pub fn ansi_prefix(&self) -> String {
+        if self.is_plain() {
+            return String::new();
+        }
+
+        let mut codes = String::new();
+        let attrs = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.strikethrough, "9"),
+        ];
+        for (_, code) in attrs.iter().filter(|(is_set, _)| *is_set) {
+            push_code(&mut codes, code);
+        }
+
+        if let Some(color) = self.fg {
+            push_separator(&mut codes);
+            color.fmt_ansi(&mut codes, false);
+        }
+
+        if let Some(color) = self.bg {
+            push_separator(&mut codes);
+            color.fmt_ansi(&mut codes, true);
+        }
+
+        format!("\u{1b}[{}m", codes)
+    }
+
+    /// Returns an ANSI sequence which resets the style, or an empty string if the style is plain.
+    pub fn ansi_suffix(&self) -> &'static str {
+        if self.is_plain() {
+            ""
+        } else {
+            "\u{1b}[0m"
+        }
+    }
+
+    /// Returns a CSS declaration of the style.
+    pub fn css(&self) -> String {
+        let mut css = Vec::new();
+        if let Some(color) = self.fg {
+            css.push(format!("color: {}", css_color(color)));
+        }
+
+        if let Some(color) = self.bg {
+            css.push(format!("background-color: {}", css_color(color)));
+        }
+
+        if self.bold {
+            css.push(String::from("font-weight: bold"));
+        }
+
+        if self.dim {
+            css.push(String::from("opacity: 0.5"));
+        }
+
+        if self.italic {
+            css.push(String::from("font-style: italic"));
+        }
+
+        match (self.underline, self.strikethrough) {
+            (true, true) => css.push(String::from("text-decoration: underline line-through")),
+            (true, false) => css.push(String::from("text-decoration: underline")),
+            (false, true) => css.push(String::from("text-decoration: line-through")),
+            (false, false) => {}
+        }
+
+        css.join("; ")
+    }
+}
+
+/// A piece of a text with a single [`TextStyle`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Span {
+    text: String,
+    style: TextStyle,
+}
+
+impl Span {
+    /// Creates a new span.
+    pub fn new<S>(text: S, style: TextStyle) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+
+    /// Returns a text of the span.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns a style of the span.
+    pub fn style(&self) -> &TextStyle {
+        &self.style
+    }
+}
+
+/// A cell content which consists of styled [`Span`]s.
+///
+/// [`RichText::as_ref`] returns a plain text, which is used to calculate width and to modify a content,
+/// while the lines which are printed contain ANSI sequences.
+///
+/// When a plain text is set to the cell (as [`Width::wrap`] or [`Format`] do),
+/// the styles are mapped onto the new text, character by character.
+///
+/// [`Width::wrap`]: crate::Width::wrap
+/// [`Format`]: crate::format::Format
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RichText {
+    spans: Vec<Span>,
+    text: String,
+    lines: Vec<Range<usize>>,
+    ansi_lines: Vec<String>,
+}
+
+impl RichText {
+    /// Creates a text from a single [`Span`].
+    pub fn styled<S>(text: S, style: TextStyle) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::from(vec![Span::new(text, style)])
+    }
+
+    /// Appends a styled text.
+    pub fn push<S>(&mut self, text: S, style: TextStyle) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        let mut spans = std::mem::take(&mut self.spans);
+        spans.push(Span::new(text, style));
+        *self = Self::from(spans);
+        self
+    }
+
+    /// Returns a list of spans.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Returns a plain text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns a text with ANSI sequences.
+    pub fn to_ansi(&self) -> String {
+        self.ansi_lines.join("\n")
+    }
+
+    /// Returns a text as a list of HTML `<span>` elements, where new lines are replaced by `<br>`.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        for span in &self.spans {
+            let text = escape_html(&span.text).replace('\n', "<br>");
+            if span.style.is_plain() {
+                html.push_str(&text);
+            } else {
+                html.push_str(&format!(
+                    "<span style=\"{}\">{}</span>",
+                    span.style.css(),
+                    text
+                ));
+            }
+        }
+
+        html
+    }
+
+    fn set_text(&mut self, text: &str) {
+        let spans = restyle(&self.spans, text);
+        *self = Self::from(spans);
+    }
+}
+
+impl From<Vec<Span>> for RichText {
+    fn from(spans: Vec<Span>) -> Self {
+        let spans = merge_spans(spans);
+        let text = spans.iter().map(|s| s.text.as_str()).collect::<String>();
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for (i, _) in text.match_indices('\n') {
+            lines.push(start..i);
+            start = i + 1;
+        }
+        lines.push(start..text.len());
+
+        let ansi_lines = render_ansi_lines(&spans, lines.len());
+
+        Self {
+            spans,
+            text,
+            lines,
+            ansi_lines,
+        }
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        Self::from(String::from(text))
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        Self::from(vec![Span::new(text, TextStyle::default())])
+    }
+}
+
+impl AsRef<str> for RichText {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for RichText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_ansi())
+    }
+}
+
+impl Cell for RichText {
+    fn get_line(&self, i: usize) -> &str {
+        &self.ansi_lines[i]
+    }
+
+    fn count_lines(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn width<W>(&self, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        (0..self.lines.len())
+            .map(|i| self.line_width(i, &width_ctrl))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn line_width<W>(&self, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        width_ctrl.width(&self.text[self.lines[i].clone()])
+    }
+}
+
+impl CellMut<String> for RichText {
+    fn set<W>(&mut self, text: String, _: W)
+    where
+        W: WidthFunc,
+    {
+        self.set_text(&text);
+    }
+
+    fn update<W>(&mut self, _: W)
+    where
+        W: WidthFunc,
+    {
+    }
+}
+
+impl CellMut<&str> for RichText {
+    fn set<W>(&mut self, text: &str, _: W)
+    where
+        W: WidthFunc,
+    {
+        self.set_text(text);
+    }
+
+    fn update<W>(&mut self, _: W)
+    where
+        W: WidthFunc,
+    {
+    }
+}
+
+impl CellMut<RichText> for RichText {
+    fn set<W>(&mut self, text: RichText, _: W)
+    where
+        W: WidthFunc,
+    {
+        *self = text;
+    }
+
+    fn update<W>(&mut self, _: W)
+    where
+        W: WidthFunc,
+    {
+    }
+}
+
+/// [`TextStyle`] can be used as a [`CellOption`] on a [`Table`] with string cells,
+/// in which case a whole text of a cell is wrapped in the style ANSI sequences.
+///
+/// [`CellOption`]: crate::CellOption
+/// [`Table`]: crate::Table
+#[cfg(feature = "color")]
+impl<R> CellOption<R> for TextStyle
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        if self.is_plain() {
+            return;
+        }
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            let text = format!("{}{}{}", self.ansi_prefix(), text, self.ansi_suffix());
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn merge_spans(spans: Vec<Span>) -> Vec<Span> {
+    let mut list: Vec<Span> = Vec::with_capacity(spans.len());
+    for span in spans {
+        if span.text.is_empty() {
+            continue;
+        }
+
+        match list.last_mut() {
+            Some(last) if last.style == span.style => last.text.push_str(&span.text),
+            _ => list.push(span),
+        }
+    }
+
+    list
+}
+
+fn render_ansi_lines(spans: &[Span], count_lines: usize) -> Vec<String> {
+    let mut lines = vec![String::new(); count_lines];
+    let mut i = 0;
+    for span in spans {
+        for (n, part) in span.text.split('\n').enumerate() {
+            if n > 0 {
+                i += 1;
+            }
+
+            if part.is_empty() {
+                continue;
+            }
+
+            let line = &mut lines[i];
+            line.push_str(&span.style.ansi_prefix());
+            line.push_str(part);
+            line.push_str(span.style.ansi_suffix());
+        }
+    }
+
+    lines
+}
+
+/// Maps styles of the spans onto a new text.
+///
+/// The characters are matched by a longest common subsequence,
+/// so inserted characters (like new lines or a suffix) are left plain,
+/// and removed characters are skipped.
+fn restyle(spans: &[Span], text: &str) -> Vec<Span> {
+    let styled = spans
+        .iter()
+        .flat_map(|span| span.text.chars().map(move |c| (c, span.style)))
+        .collect::<Vec<_>>();
+    let chars = text.chars().collect::<Vec<_>>();
+
+    let styles = match_chars(&styled, &chars);
+
+    let mut list: Vec<Span> = Vec::new();
+    for (c, style) in chars.into_iter().zip(styles) {
+        match list.last_mut() {
+            Some(last) if last.style == style => last.text.push(c),
+            _ => list.push(Span::new(c.to_string(), style)),
+        }
+    }
+
+    list
+}
+
+fn match_chars(styled: &[(char, TextStyle)], chars: &[char]) -> Vec<TextStyle> {
+    // a limit of a LCS table after which we fallback to a greedy matching.
+    const MAX_TABLE_SIZE: usize = 1 << 20;
+
+    let mut styles = vec![TextStyle::default(); chars.len()];
+
+    let (n, m) = (styled.len(), chars.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_TABLE_SIZE {
+        let mut cursor = 0;
+        for (i, &c) in chars.iter().enumerate() {
+            if let Some(shift) = styled[cursor..].iter().position(|&(sc, _)| sc == c) {
+                styles[i] = styled[cursor + shift].1;
+                cursor += shift + 1;
+            }
+        }
+
+        return styles;
+    }
+
+    let mut table = vec![0u32; (n + 1) * (m + 1)];
+    let index = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[index(i, j)] = if styled[i].0 == chars[j] {
+                table[index(i + 1, j + 1)] + 1
+            } else {
+                std::cmp::max(table[index(i + 1, j)], table[index(i, j + 1)])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if styled[i].0 == chars[j] {
+            styles[j] = styled[i].1;
+            i += 1;
+            j += 1;
+        } else if table[index(i + 1, j)] >= table[index(i, j + 1)] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    styles
+}
+
+fn push_separator(codes: &mut String) {
+    if !codes.is_empty() {
+        codes.push(';');
+    }
+}
+
+fn push_code(codes: &mut String, code: &str) {
+    push_separator(codes);
+    codes.push_str(code);
+}
+
+fn css_color(color: TextColor) -> String {
+    let (r, g, b) = color.to_rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+pub(crate) fn fixed_to_rgb(n: u8) -> (u8, u8, u8) {
+    const BASE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match n {
+        0..=15 => BASE[n as usize],
+        16..=231 => {
+            let n = n - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(n / 36), level((n / 6) % 6), level(n % 6))
+        }
+        _ => {
+            let v = 8 + (n - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restyle_test() {
+        let red = TextStyle::new().fg(TextColor::Red);
+        let spans = [
+            Span::new("Hello", red),
+            Span::new(" World", TextStyle::new()),
+        ];
+
+        assert_eq!(
+            restyle(&spans, "Hel\nlo World"),
+            [
+                Span::new("Hel", red),
+                Span::new("\n", TextStyle::new()),
+                Span::new("lo", red),
+                Span::new(" World", TextStyle::new()),
+            ]
+        );
+        assert_eq!(
+            restyle(&spans, "> Hello World"),
+            [
+                Span::new("> ", TextStyle::new()),
+                Span::new("Hello", red),
+                Span::new(" World", TextStyle::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn fixed_to_rgb_test() {
+        assert_eq!(fixed_to_rgb(16), (0, 0, 0));
+        assert_eq!(fixed_to_rgb(196), (255, 0, 0));
+        assert_eq!(fixed_to_rgb(231), (255, 255, 255));
+        assert_eq!(fixed_to_rgb(244), (128, 128, 128));
+    }
+}
@@ -26,14 +26,79 @@
 //! );
 //! ```
 //!
+//! Being used with [`Modify`] [`Rotate`] rotates a text of cells,
+//! which is handy for long headers of narrow columns.
+//! [`VerticalText`] stacks characters of a text instead.
+//!
+//! ```
+//! use tabled::{object::Rows, Modify, Rotate, TableIteratorExt};
+//!
+//! let data = [[1, 2, 3], [4, 5, 6]];
+//!
+//! let table = data
+//!     .table()
+//!     .with(Modify::new(Rows::first()).with(Rotate::Left))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---+---+---+\n",
+//!         "| 0 | 1 | 2 |\n",
+//!         "+---+---+---+\n",
+//!         "| 1 | 2 | 3 |\n",
+//!         "+---+---+---+\n",
+//!         "| 4 | 5 | 6 |\n",
+//!         "+---+---+---+",
+//!     )
+//! );
+//! ```
+//!
 //! [`Table`]: crate::Table
 //! [`Builder::index`]: crate::builder::Builder::index
+//! [`Modify`]: crate::Modify
 
-use papergrid::records::{Records, Resizable};
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+    Entity,
+};
 
-use crate::{Table, TableOption};
+use crate::{CellOption, Table, TableOption};
 
 /// Rotate can be used to rotate a table by 90 degrees.
+///
+/// Being used as a [`CellOption`] it rotates a text of a cell,
+/// so its lines become columns (by [`Rotate::Left`] and [`Rotate::Right`])
+/// or their order is reversed (by [`Rotate::Top`] and [`Rotate::Bottom`]).
+/// The rotated text is padded to a rectangle,
+/// and a width and a height of the cell are changed accordingly.
+///
+/// ```
+/// use tabled::{object::Cell, Modify, Rotate, Table};
+///
+/// let table = Table::new([("Hello", "World")])
+///     .with(Modify::new(Cell(1, 0)).with(Rotate::Left))
+///     .with(Modify::new(Cell(1, 1)).with(Rotate::Right))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------+------+\n",
+///         "| &str | &str |\n",
+///         "+------+------+\n",
+///         "| o    | W    |\n",
+///         "| l    | o    |\n",
+///         "| l    | r    |\n",
+///         "| e    | l    |\n",
+///         "| H    | d    |\n",
+///         "+------+------+",
+///     )
+/// );
+/// ```
+///
+/// Notice that with a `color` feature ANSI sequences are removed from a rotated text.
 #[derive(Debug)]
 pub enum Rotate {
     /// Rotate [`Table`] to the left.
@@ -148,3 +213,133 @@ where
         }
     }
 }
+
+impl<R> CellOption<R> for Rotate
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        change_text(table, entity, |text| rotate_text(text, self));
+    }
+}
+
+/// [`VerticalText`] stacks characters of a cell text one per line.
+///
+/// Each line of a text becomes a column, which is read from top to bottom.
+/// A width and a height of the cell are changed accordingly.
+///
+/// Notice that with a `color` feature ANSI sequences are removed from a text.
+///
+/// ```
+/// use tabled::{object::Rows, Modify, Table, VerticalText};
+///
+/// let table = Table::new([(1, "a")])
+///     .with(Modify::new(Rows::first()).with(VerticalText))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+---+---+\n",
+///         "| i | & |\n",
+///         "| 3 | s |\n",
+///         "| 2 | t |\n",
+///         "|   | r |\n",
+///         "+---+---+\n",
+///         "| 1 | a |\n",
+///         "+---+---+",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VerticalText;
+
+impl<R> CellOption<R> for VerticalText
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        change_text(table, entity, |text| {
+            let lines = get_chars(text);
+            let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+            build_text(width, lines.len(), |row, col| lines[col].get(row))
+        });
+    }
+}
+
+fn change_text<R, F>(table: &mut Table<R>, entity: Entity, f: F)
+where
+    R: Records + RecordsMut<String>,
+    F: Fn(&str) -> String,
+{
+    let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+    let (count_rows, count_cols) = table.shape();
+    for pos in entity.iter(count_rows, count_cols) {
+        let text = f(table.get_records().get_text(pos));
+        table.get_records_mut().set(pos, text, &width_fn);
+    }
+
+    table.destroy_width_cache();
+    table.destroy_height_cache();
+}
+
+fn rotate_text(text: &str, rotate: &Rotate) -> String {
+    let lines = get_chars(text);
+    let count_lines = lines.len();
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+
+    match rotate {
+        Rotate::Left => build_text(width, count_lines, |row, col| {
+            lines[col].get(width - row - 1)
+        }),
+        Rotate::Right => build_text(width, count_lines, |row, col| {
+            lines[count_lines - col - 1].get(row)
+        }),
+        Rotate::Top | Rotate::Bottom => build_text(count_lines, width, |row, col| {
+            lines[count_lines - row - 1].get(col)
+        }),
+    }
+}
+
+fn get_chars(text: &str) -> Vec<Vec<char>> {
+    #[cfg(feature = "color")]
+    let text = ansi_str::AnsiStr::ansi_strip(text);
+
+    text.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Builds a text from a grid of chars, padding each column to its widest char.
+fn build_text<'a, F>(count_rows: usize, count_cols: usize, get: F) -> String
+where
+    F: Fn(usize, usize) -> Option<&'a char>,
+{
+    let char_width = |c: Option<&char>| {
+        c.map_or(1, |&c| {
+            unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+        })
+    };
+
+    let widths = (0..count_cols)
+        .map(|col| {
+            (0..count_rows)
+                .map(|row| char_width(get(row, col)))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let mut buf = String::new();
+    for row in 0..count_rows {
+        if row > 0 {
+            buf.push('\n');
+        }
+
+        for (col, &width) in widths.iter().enumerate() {
+            let c = get(row, col);
+            buf.push(c.copied().unwrap_or(' '));
+            buf.push_str(&" ".repeat(width.saturating_sub(char_width(c))));
+        }
+    }
+
+    buf
+}
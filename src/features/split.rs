@@ -0,0 +1,540 @@
+//! This module contains a [`Split`] structure which is used to
+//! divide a [`Table`] into a few smaller ones, e.g. pages.
+//!
+//! [`Table`]: crate::Table
+
+use std::{collections::BTreeSet, ops::Range};
+
+use papergrid::{
+    records::{Records, Resizable},
+    Border, Entity, GridConfig, Offset, Position,
+};
+
+use crate::{
+    height::get_table_total_height2,
+    object::{Columns, Object, Rows},
+    width::get_table_widths,
+    Table,
+};
+
+/// Split divides a [`Table`] into chunks which fit a given height or width.
+///
+/// Some rows and columns can be marked as sticky,
+/// so they are repeated in each chunk at their original places.
+/// It might be a header row, a footer or a column of an index.
+///
+/// Each chunk is an ordinary [`Table`] with the same [`Style`],
+/// so it has its own top and bottom borders.
+/// Settings of cells and lines (like padding, alignment, colors or [`Highlight`])
+/// are moved along with them.
+/// Spans are cut by the chunks borders.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{object::Rows, Split, Style, Table};
+///
+/// let data = [("Alice", 95), ("Bob", 70), ("Eve", 40)];
+///
+/// let mut table = Table::new(data);
+/// table.with(Style::modern());
+///
+/// let pages = Split::height(7).sticky_rows(Rows::first()).split(&table);
+///
+/// assert_eq!(pages.len(), 2);
+/// assert_eq!(
+///     pages[1].to_string(),
+///     concat!(
+///         "┌──────┬─────┐\n",
+///         "│ &str │ i32 │\n",
+///         "├──────┼─────┤\n",
+///         "│ Eve  │ 40  │\n",
+///         "└──────┴─────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Style`]: crate::Style
+/// [`Highlight`]: crate::Highlight
+#[derive(Debug, Clone)]
+pub struct Split<R, C> {
+    limit: usize,
+    direction: Direction,
+    rows: R,
+    columns: C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Height,
+    Width,
+}
+
+impl Split<Rows<Range<usize>>, Columns<Range<usize>>> {
+    /// Splits a table by rows, so each chunk is not higher than a given height.
+    ///
+    /// A chunk has at least 1 not sticky row even if it doesn't fit.
+    pub fn height(limit: usize) -> Self {
+        Self::new(limit, Direction::Height)
+    }
+
+    /// Splits a table by columns, so each chunk is not wider than a given width.
+    ///
+    /// A chunk has at least 1 not sticky column even if it doesn't fit.
+    pub fn width(limit: usize) -> Self {
+        Self::new(limit, Direction::Width)
+    }
+
+    fn new(limit: usize, direction: Direction) -> Self {
+        Self {
+            limit,
+            direction,
+            rows: Rows::new(0..0),
+            columns: Columns::new(0..0),
+        }
+    }
+}
+
+impl<R, C> Split<R, C> {
+    /// Sets rows which are repeated in each chunk.
+    ///
+    /// It's [`Rows::first`] for a header or a [`Panel`] added by [`Panel::header`].
+    ///
+    /// [`Rows::first`]: crate::object::Rows::first
+    /// [`Panel`]: crate::Panel
+    /// [`Panel::header`]: crate::Panel::header
+    pub fn sticky_rows<O>(self, rows: O) -> Split<O, C>
+    where
+        O: Object,
+    {
+        Split {
+            limit: self.limit,
+            direction: self.direction,
+            rows,
+            columns: self.columns,
+        }
+    }
+
+    /// Sets columns which are repeated in each chunk.
+    ///
+    /// It's [`Columns::first`] for an index created by [`Builder::index`].
+    ///
+    /// [`Columns::first`]: crate::object::Columns::first
+    /// [`Builder::index`]: crate::builder::Builder::index
+    pub fn sticky_columns<O>(self, columns: O) -> Split<R, O>
+    where
+        O: Object,
+    {
+        Split {
+            limit: self.limit,
+            direction: self.direction,
+            rows: self.rows,
+            columns,
+        }
+    }
+}
+
+impl<R, C> Split<R, C>
+where
+    R: Object,
+    C: Object,
+{
+    /// Splits a table into chunks.
+    ///
+    /// A table which has nothing to split is returned as the only chunk.
+    pub fn split<T>(&self, table: &Table<T>) -> Vec<Table<T>>
+    where
+        T: Records + Resizable + Clone,
+    {
+        let (count_rows, count_cols) = table.shape();
+        let sticky_rows = collect_lines(table, &self.rows, |(row, _)| row);
+        let sticky_columns = collect_lines(table, &self.columns, |(_, col)| col);
+
+        let all_rows = (0..count_rows).collect::<Vec<_>>();
+        let all_columns = (0..count_cols).collect::<Vec<_>>();
+
+        let cfg = table.get_config();
+        let chunks = match self.direction {
+            Direction::Height => {
+                let (_, heights) = get_table_total_height2(table.get_records(), cfg);
+                let get_height = |rows: &[usize]| get_chunk_height(cfg, &heights, rows);
+                split_lines(count_rows, &sticky_rows, self.limit, get_height)
+            }
+            Direction::Width => {
+                let widths = get_table_widths(table.get_records(), cfg);
+                let get_width = |columns: &[usize]| get_chunk_width(cfg, &widths, columns);
+                split_lines(count_cols, &sticky_columns, self.limit, get_width)
+            }
+        };
+
+        if chunks.len() < 2 {
+            return vec![table.clone()];
+        }
+
+        chunks
+            .into_iter()
+            .map(|lines| match self.direction {
+                Direction::Height => create_chunk(table, &lines, &all_columns),
+                Direction::Width => create_chunk(table, &all_rows, &lines),
+            })
+            .collect()
+    }
+}
+
+fn collect_lines<T, O, F>(table: &Table<T>, object: &O, get: F) -> BTreeSet<usize>
+where
+    T: Records,
+    O: Object,
+    F: Fn(Position) -> usize,
+{
+    let (count_rows, count_cols) = table.shape();
+    object
+        .cells(table)
+        .flat_map(|entity| entity.iter(count_rows, count_cols))
+        .map(get)
+        .collect()
+}
+
+/// Splits not sticky lines (rows or columns) greedily,
+/// each chunk contains sticky lines in sorted order.
+fn split_lines<F>(count: usize, sticky: &BTreeSet<usize>, limit: usize, size: F) -> Vec<Vec<usize>>
+where
+    F: Fn(&[usize]) -> usize,
+{
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    for line in (0..count).filter(|i| !sticky.contains(i)) {
+        chunk.push(line);
+
+        let lines = merge_lines(sticky, &chunk);
+        if chunk.len() > 1 && size(&lines) > limit {
+            chunk.pop();
+            chunks.push(merge_lines(sticky, &chunk));
+            chunk = vec![line];
+        }
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(merge_lines(sticky, &chunk));
+    }
+
+    chunks
+}
+
+fn merge_lines(sticky: &BTreeSet<usize>, lines: &[usize]) -> Vec<usize> {
+    let mut lines = sticky
+        .iter()
+        .copied()
+        .chain(lines.iter().copied())
+        .collect::<Vec<_>>();
+    lines.sort_unstable();
+    lines
+}
+
+fn get_chunk_height(cfg: &GridConfig, heights: &[usize], rows: &[usize]) -> usize {
+    let margin = cfg.get_margin();
+    let count_lines = (0..=rows.len())
+        .filter(|&i| cfg.has_horizontal(i, rows.len()))
+        .count();
+
+    rows.iter().map(|&row| heights[row]).sum::<usize>()
+        + count_lines
+        + margin.top.size
+        + margin.bottom.size
+}
+
+fn get_chunk_width(cfg: &GridConfig, widths: &[usize], columns: &[usize]) -> usize {
+    let margin = cfg.get_margin();
+    let count_lines = (0..=columns.len())
+        .filter(|&i| cfg.has_vertical(i, columns.len()))
+        .count();
+
+    columns.iter().map(|&col| widths[col]).sum::<usize>()
+        + count_lines
+        + margin.left.size
+        + margin.right.size
+}
+
+fn create_chunk<T>(table: &Table<T>, rows: &[usize], columns: &[usize]) -> Table<T>
+where
+    T: Records + Resizable + Clone,
+{
+    let shape = table.shape();
+    let cfg = table.get_config();
+    let column_spans = cfg.iter_column_spans(shape).collect::<Vec<_>>();
+    let row_spans = cfg.iter_row_spans(shape).collect::<Vec<_>>();
+
+    let mut chunk = table.clone();
+
+    // a span is cut to the lines which are in the chunk,
+    // and its text is moved to the first of them.
+    let mut spans = Vec::new();
+    for ((row, col), span) in column_spans {
+        let (new_row, (new_col, length)) = match (find(rows, row), cut_span(columns, col, span)) {
+            (Some(new_row), Some(cut)) => (new_row, cut),
+            _ => continue,
+        };
+
+        chunk
+            .get_records_mut()
+            .swap((row, col), (row, columns[new_col]));
+        spans.push(((new_row, new_col), length, true));
+    }
+    for ((row, col), span) in row_spans {
+        let (new_col, (new_row, length)) = match (find(columns, col), cut_span(rows, row, span)) {
+            (Some(new_col), Some(cut)) => (new_col, cut),
+            _ => continue,
+        };
+
+        chunk
+            .get_records_mut()
+            .swap((row, col), (rows[new_row], col));
+        spans.push(((new_row, new_col), length, false));
+    }
+
+    let records = chunk.get_records_mut();
+    for row in (0..shape.0).rev().filter(|&row| find(rows, row).is_none()) {
+        records.remove_row(row);
+    }
+    for col in (0..shape.1)
+        .rev()
+        .filter(|&col| find(columns, col).is_none())
+    {
+        records.remove_column(col);
+    }
+
+    let mut cfg = create_chunk_config(table, rows, columns);
+    for (pos, length, is_column_span) in spans {
+        if is_column_span {
+            cfg.set_column_span(pos, length);
+        } else {
+            cfg.set_row_span(pos, length);
+        }
+    }
+
+    *chunk.get_config_mut() = cfg;
+    chunk.destroy_width_cache();
+    chunk.destroy_height_cache();
+
+    chunk
+}
+
+/// Creates a configuration of a chunk,
+/// where settings of cells and lines are moved to their new positions.
+fn create_chunk_config<T>(table: &Table<T>, rows: &[usize], columns: &[usize]) -> GridConfig
+where
+    T: Records,
+{
+    let shape = table.shape();
+    let cfg = table.get_config();
+
+    // a theme is a configuration without borders set to particular cells,
+    // so they can be distinguished and moved.
+    let mut theme = cfg.clone();
+    for (pos, _) in cfg.iter_column_spans(shape).collect::<Vec<_>>() {
+        theme.set_column_span(pos, 1);
+    }
+    for (pos, _) in cfg.iter_row_spans(shape).collect::<Vec<_>>() {
+        theme.set_row_span(pos, 1);
+    }
+    for row in 0..shape.0 {
+        for col in 0..shape.1 {
+            theme.remove_border((row, col), shape);
+            #[cfg(feature = "color")]
+            theme.remove_border_color((row, col), shape);
+        }
+    }
+
+    let mut chunk = theme.clone();
+    move_lines(cfg, &mut chunk, table, rows, columns);
+
+    for (i, &row) in rows.iter().enumerate() {
+        for (j, &col) in columns.iter().enumerate() {
+            let (from, to) = (Entity::Cell(row, col), Entity::Cell(i, j));
+            chunk.set_padding(to, *cfg.get_padding(from));
+            chunk.set_formatting(to, *cfg.get_formatting(from));
+            chunk.set_alignment_horizontal(to, *cfg.get_alignment_horizontal(from));
+            chunk.set_alignment_vertical(to, *cfg.get_alignment_vertical(from));
+
+            let border = cfg.get_border((row, col), shape);
+            let default = theme.get_border((row, col), shape);
+            chunk.set_border((i, j), diff_border(border, default));
+
+            #[cfg(feature = "color")]
+            {
+                let color = cfg.get_padding_color(from);
+                let color = papergrid::Sides::new(
+                    own_color(&color.left),
+                    own_color(&color.right),
+                    own_color(&color.top),
+                    own_color(&color.bottom),
+                );
+                chunk.set_padding_color(to, color);
+
+                let border = cfg.get_border_color((row, col), shape);
+                let default = theme.get_border_color((row, col), shape);
+                let border = diff_border(border, default);
+                chunk.set_border_color((i, j), map_border(border, own_color));
+            }
+        }
+    }
+
+    chunk
+}
+
+/// Moves horizontal and vertical lines, texts on them and overridden chars
+/// to the lines of a chunk.
+fn move_lines<T>(
+    cfg: &GridConfig,
+    chunk: &mut GridConfig,
+    table: &Table<T>,
+    rows: &[usize],
+    columns: &[usize],
+) where
+    T: Records,
+{
+    let (count_rows, count_cols) = table.shape();
+    let widths = get_table_widths(table.get_records(), cfg);
+    let (_, heights) = get_table_total_height2(table.get_records(), cfg);
+
+    for row in 0..=count_rows {
+        chunk.remove_horizontal_line(row);
+        chunk.remove_split_line_text(row);
+        for col in 0..count_cols {
+            chunk.remove_overidden_horizontal((row, col));
+        }
+    }
+    for col in 0..=count_cols {
+        chunk.remove_vertical_line(col);
+        for row in 0..count_rows {
+            chunk.remove_overidden_vertical((row, col));
+        }
+    }
+
+    for i in 0..=rows.len() {
+        if let Some(row) = source_line(rows, i, |row| cfg.get_horizontal_line(row).is_some()) {
+            if let Some(line) = cfg.get_horizontal_line(row) {
+                chunk.set_horizontal_line(i, *line);
+            }
+        }
+
+        if let Some(row) = source_line(rows, i, |row| cfg.get_split_line_text(row).is_some()) {
+            if let (Some(text), Some(offset)) =
+                (cfg.get_split_line_text(row), cfg.get_split_line_offset(row))
+            {
+                chunk.override_split_line(i, text, offset);
+            }
+        }
+
+        for (j, &col) in columns.iter().enumerate() {
+            let is_set = |row| cfg.is_overidden_horizontal((row, col));
+            if let Some(row) = source_line(rows, i, is_set) {
+                let width = widths[col];
+                for offset in 0..width {
+                    if let Some(c) = cfg.lookup_overidden_horizontal((row, col), offset, width) {
+                        chunk.override_horizontal_border((i, j), c, Offset::Begin(offset));
+                    }
+                }
+            }
+        }
+    }
+
+    for j in 0..=columns.len() {
+        if let Some(col) = source_line(columns, j, |col| cfg.get_vertical_line(col).is_some()) {
+            if let Some(line) = cfg.get_vertical_line(col) {
+                chunk.set_vertical_line(j, *line);
+            }
+        }
+
+        for (i, &row) in rows.iter().enumerate() {
+            let is_set = |col| cfg.is_overidden_vertical((row, col));
+            if let Some(col) = source_line(columns, j, is_set) {
+                let height = heights[row];
+                for offset in 0..height {
+                    if let Some(c) = cfg.lookup_overidden_vertical((row, col), offset, height) {
+                        chunk.override_vertical_border((i, j), c, Offset::Begin(offset));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns an original index of a line before a given line of a chunk, which has some setting.
+///
+/// A line after a previous line of a chunk goes first,
+/// so a line under a sticky header is the one from the original header.
+fn source_line<F>(lines: &[usize], i: usize, is_set: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool,
+{
+    let after_previous = i.checked_sub(1).map(|i| lines[i] + 1);
+    let before_next = lines.get(i).copied();
+
+    after_previous
+        .into_iter()
+        .chain(before_next)
+        .find(|&line| is_set(line))
+}
+
+/// Returns a border which has only sides different from a default one.
+fn diff_border<T>(border: Border<T>, default: Border<T>) -> Border<T>
+where
+    T: PartialEq,
+{
+    let diff = |side: Option<T>, default: Option<T>| if side != default { side } else { None };
+
+    Border {
+        top: diff(border.top, default.top),
+        bottom: diff(border.bottom, default.bottom),
+        left: diff(border.left, default.left),
+        right: diff(border.right, default.right),
+        left_top_corner: diff(border.left_top_corner, default.left_top_corner),
+        left_bottom_corner: diff(border.left_bottom_corner, default.left_bottom_corner),
+        right_top_corner: diff(border.right_top_corner, default.right_top_corner),
+        right_bottom_corner: diff(border.right_bottom_corner, default.right_bottom_corner),
+    }
+}
+
+#[cfg(feature = "color")]
+fn map_border<T, U, F>(border: Border<T>, f: F) -> Border<U>
+where
+    F: Fn(T) -> U,
+{
+    Border {
+        top: border.top.map(&f),
+        bottom: border.bottom.map(&f),
+        left: border.left.map(&f),
+        right: border.right.map(&f),
+        left_top_corner: border.left_top_corner.map(&f),
+        left_bottom_corner: border.left_bottom_corner.map(&f),
+        right_top_corner: border.right_top_corner.map(&f),
+        right_bottom_corner: border.right_bottom_corner.map(&f),
+    }
+}
+
+#[cfg(feature = "color")]
+fn own_color(color: &papergrid::AnsiColor<'_>) -> papergrid::AnsiColor<'static> {
+    papergrid::AnsiColor::new(
+        color.get_prefix().to_owned().into(),
+        color.get_suffix().to_owned().into(),
+    )
+}
+
+fn find(lines: &[usize], line: usize) -> Option<usize> {
+    lines.binary_search(&line).ok()
+}
+
+/// Returns an index of a first line of a span in a chunk and a new span length.
+fn cut_span(lines: &[usize], start: usize, span: usize) -> Option<(usize, usize)> {
+    let first = lines
+        .iter()
+        .position(|&line| line >= start && line < start + span)?;
+    let length = lines[first..]
+        .iter()
+        .take_while(|&&line| line < start + span)
+        .count();
+
+    Some((first, length))
+}
@@ -1,13 +1,34 @@
 use std::borrow::Cow;
 
-use papergrid::records::Records;
+use papergrid::{
+    records::Records,
+    util::{spplit_str_at, string_width},
+    AlignmentHorizontal, AlignmentVertical, GridConfig,
+};
 
-use crate::{Table, TableOption};
+use crate::{
+    height::get_table_total_height2, width::get_table_widths, Alignment, Table, TableOption,
+};
 
-use super::Offset;
+#[cfg(feature = "color")]
+use crate::color::Color;
+
+use super::{junction_correction::intersection_border, Offset};
 
 /// [`BorderText`] writes a custom text on a border.
 ///
+/// A text can be written on a horizontal line ([`BorderText::new`])
+/// as well as on a vertical one ([`BorderText::vertical`]), where it's stacked one char per line.
+///
+/// A text may be bound to a column (or a row) by [`BorderText::column`] ([`BorderText::row`]),
+/// so an [`Offset`] and an [`Alignment`] are relative to the cell and its span.
+///
+/// Several texts can be set on a single line;
+/// in case they overlap the latest applied one wins.
+///
+/// Notice that a text is positioned by current widths of columns,
+/// so it must be set after all options which change them.
+///
 /// # Example
 ///
 /// ```rust
@@ -26,13 +47,37 @@ use super::Offset;
 ///      +-------------+"
 /// );
 /// ```
+///
+/// ```rust
+/// use tabled::{Alignment, BorderText, Style, Table};
+///
+/// let mut table = Table::new([("Hello", "World")]);
+/// table
+///     .with(Style::modern())
+///     .with(BorderText::first("A").column(0).alignment(Alignment::center()))
+///     .with(BorderText::first("B").column(1).alignment(Alignment::right()))
+///     .with(BorderText::left("ok").row(1));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌───A───┬──────B┐\n\
+///      │ &str  │ &str  │\n\
+///      ├───────┼───────┤\n\
+///      o Hello │ World │\n\
+///      k───────┴───────┘"
+/// );
+/// ```
 #[derive(Debug)]
 pub struct BorderText<'a> {
-    // todo: offset from which we start overriding border
-    // offset: usize,
     text: Cow<'a, str>,
-    row: SplitLineIndex,
+    line: SplitLineIndex,
+    vertical: bool,
+    column: Option<usize>,
+    row: Option<usize>,
     offset: Offset,
+    alignment: Option<Alignment>,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
 }
 
 #[derive(Debug)]
@@ -51,11 +96,7 @@ impl<'a> BorderText<'a> {
     where
         S: Into<Cow<'a, str>>,
     {
-        Self {
-            text: text.into(),
-            row: SplitLineIndex::Line(line),
-            offset: Offset::Begin(0),
-        }
+        Self::create(text, SplitLineIndex::Line(line), false)
     }
 
     /// Creates a [`BorderText`] instance for a top line.
@@ -63,30 +104,133 @@ impl<'a> BorderText<'a> {
     where
         S: Into<Cow<'a, str>>,
     {
-        Self {
-            text: text.into(),
-            row: SplitLineIndex::First,
-            offset: Offset::Begin(0),
-        }
+        Self::create(text, SplitLineIndex::First, false)
     }
 
     /// Creates a [`BorderText`] instance for a bottom line.
     pub fn last<S>(text: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::create(text, SplitLineIndex::Last, false)
+    }
+
+    /// Creates a [`BorderText`] instance for a vertical line.
+    ///
+    /// Lines are numbered from 0 to the `count_columns` included
+    /// (`line >= 0 && line <= count_columns`).
+    ///
+    /// A text is written one char per line from top to bottom,
+    /// including intersections with horizontal lines.
+    pub fn vertical<S>(line: usize, text: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::create(text, SplitLineIndex::Line(line), true)
+    }
+
+    /// Creates a [`BorderText`] instance for a left line.
+    pub fn left<S>(text: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::create(text, SplitLineIndex::First, true)
+    }
+
+    /// Creates a [`BorderText`] instance for a right line.
+    pub fn right<S>(text: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::create(text, SplitLineIndex::Last, true)
+    }
+
+    fn create<S>(text: S, line: SplitLineIndex, vertical: bool) -> Self
     where
         S: Into<Cow<'a, str>>,
     {
         Self {
             text: text.into(),
-            row: SplitLineIndex::Last,
+            line,
+            vertical,
+            column: None,
+            row: None,
             offset: Offset::Begin(0),
+            alignment: None,
+            #[cfg(feature = "color")]
+            color: None,
         }
     }
 
     /// Set an offset from which the text will be started.
+    ///
+    /// If a text is bound to a column (a row), the offset is relative to it.
     pub fn offset(mut self, offset: Offset) -> Self {
         self.offset = offset;
         self
     }
+
+    /// Binds a text on a horizontal line to a column.
+    ///
+    /// If a cell at the line spans several columns the text is bound to all of them.
+    /// It's ignored for a vertical line.
+    pub fn column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Binds a text on a vertical line to a row.
+    ///
+    /// If a cell at the line spans several rows the text is bound to all of them.
+    /// It's ignored for a horizontal line.
+    pub fn row(mut self, row: usize) -> Self {
+        self.row = Some(row);
+        self
+    }
+
+    /// Aligns a text on a line (or inside a column/row it's bound to).
+    ///
+    /// A horizontal line uses [`Alignment::Horizontal`],
+    /// while a vertical line uses [`Alignment::Vertical`];
+    /// an alignment of a different kind is ignored.
+    ///
+    /// An alignment takes precedence over an [`Offset`].
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets a color of a text.
+    ///
+    /// A vertical text is colored by border colors,
+    /// so a color is applied to the whole border of each cell the text touches.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn get_line(&self, count_lines: usize) -> Option<usize> {
+        match self.line {
+            SplitLineIndex::First => Some(0),
+            SplitLineIndex::Last => Some(count_lines),
+            SplitLineIndex::Line(line) if line <= count_lines => Some(line),
+            SplitLineIndex::Line(_) => None,
+        }
+    }
+
+    fn get_text(&self) -> Cow<'_, str> {
+        #[cfg(feature = "color")]
+        if let Some(color) = &self.color {
+            let color = papergrid::AnsiColor::from(color.clone());
+            let text = format!("{}{}{}", color.get_prefix(), self.text, color.get_suffix());
+
+            return Cow::Owned(text);
+        }
+
+        Cow::Borrowed(self.text.as_ref())
+    }
 }
 
 impl<'a, R> TableOption<R> for BorderText<'a>
@@ -94,20 +238,295 @@ where
     R: Records,
 {
     fn change(&mut self, table: &mut Table<R>) {
-        let row = match self.row {
-            SplitLineIndex::First => 0,
-            SplitLineIndex::Last => table.shape().0,
-            SplitLineIndex::Line(row) => {
-                if row > table.shape().0 {
-                    return;
+        if self.vertical {
+            set_vertical_text(self, table);
+        } else {
+            set_horizontal_text(self, table);
+        }
+    }
+}
+
+fn set_horizontal_text<R>(border: &BorderText<'_>, table: &mut Table<R>)
+where
+    R: Records,
+{
+    let shape = table.shape();
+    let row = match border.get_line(shape.0) {
+        Some(row) => row,
+        None => return,
+    };
+
+    let text = border.get_text();
+    let alignment = match border.alignment {
+        Some(Alignment::Horizontal(alignment)) => Some(alignment),
+        _ => None,
+    };
+
+    let cfg = table.get_config();
+    let is_plain = border.column.is_none() && alignment.is_none();
+    if is_plain && cfg.get_split_line_text(row).is_none() {
+        table
+            .get_config_mut()
+            .override_split_line(row, text, border.offset.into());
+        return;
+    }
+
+    if shape.0 == 0 || shape.1 == 0 {
+        return;
+    }
+
+    let widths = get_table_widths(table.get_records(), cfg);
+    let (line, starts) = build_split_line(cfg, row, &widths, shape);
+
+    let (start, end) = match border.column {
+        Some(col) if col < shape.1 => {
+            let cell_row = if row == shape.0 { row - 1 } else { row };
+            let span = cfg.get_column_span((cell_row, col), shape).unwrap_or(1);
+            let last = std::cmp::min(col + span, shape.1) - 1;
+            (starts[col], starts[last] + widths[last])
+        }
+        Some(_) => return,
+        None => (0, line.len()),
+    };
+
+    let side = alignment.map(|alignment| match alignment {
+        AlignmentHorizontal::Left => Side::Start,
+        AlignmentHorizontal::Center => Side::Center,
+        AlignmentHorizontal::Right => Side::End,
+    });
+
+    let pos = text_position(start, end, string_width(&text), border.offset, side);
+
+    let mut labels = Vec::with_capacity(2);
+    let cfg = table.get_config_mut();
+    if let Some((text, offset)) = cfg.remove_split_line_text(row) {
+        let text = text.lines().next().unwrap_or_default().to_owned();
+        labels.push((offset_start_pos(offset, line.len()), text));
+    }
+
+    labels.push((pos, text.into_owned()));
+
+    let (pos, text) = compose_labels(&line, &labels);
+    cfg.override_split_line(row, text, papergrid::Offset::Begin(pos));
+}
+
+fn set_vertical_text<R>(border: &BorderText<'_>, table: &mut Table<R>)
+where
+    R: Records,
+{
+    let shape = table.shape();
+    if shape.0 == 0 || shape.1 == 0 {
+        return;
+    }
+
+    let col = match border.get_line(shape.1) {
+        Some(col) => col,
+        None => return,
+    };
+
+    let cfg = table.get_config();
+    if !cfg.has_vertical(col, shape.1) {
+        return;
+    }
+
+    let (_, heights) = get_table_total_height2(table.get_records(), cfg);
+
+    let mut slots = Vec::new();
+    let mut starts = Vec::with_capacity(shape.0);
+    for (row, &height) in heights.iter().enumerate() {
+        if cfg.has_horizontal(row, shape.0) {
+            slots.push(Slot::Intersection(row));
+        }
+
+        starts.push(slots.len());
+        slots.extend((0..height).map(|i| Slot::Line(row, i)));
+    }
+
+    if cfg.has_horizontal(shape.0, shape.0) {
+        slots.push(Slot::Intersection(shape.0));
+    }
+
+    let (start, end) = match border.row {
+        Some(row) if row < shape.0 => {
+            let cell_col = if col == shape.1 { col - 1 } else { col };
+            let span = cfg.get_row_span((row, cell_col), shape).unwrap_or(1);
+            let last = std::cmp::min(row + span, shape.0) - 1;
+            (starts[row], starts[last] + heights[last])
+        }
+        Some(_) => return,
+        None => (0, slots.len()),
+    };
+
+    let side = match border.alignment {
+        Some(Alignment::Vertical(AlignmentVertical::Top)) => Some(Side::Start),
+        Some(Alignment::Vertical(AlignmentVertical::Center)) => Some(Side::Center),
+        Some(Alignment::Vertical(AlignmentVertical::Bottom)) => Some(Side::End),
+        _ => None,
+    };
+
+    let chars = border.text.chars().collect::<Vec<_>>();
+    let pos = text_position(start, end, chars.len(), border.offset, side);
+
+    let cfg = table.get_config_mut();
+    for (slot, c) in slots.into_iter().skip(pos).zip(chars) {
+        match slot {
+            Slot::Intersection(row) => {
+                let (pos, b) = intersection_border((row, col), c, shape);
+                cfg.set_border(pos, b);
+            }
+            Slot::Line(row, i) => {
+                cfg.override_vertical_border((row, col), c, papergrid::Offset::Begin(i));
+            }
+        }
+
+        #[cfg(feature = "color")]
+        if let Some(color) = &border.color {
+            let color = papergrid::AnsiColor::from(color.clone());
+            let (pos, b) = match slot {
+                Slot::Intersection(row) => intersection_border((row, col), color, shape),
+                Slot::Line(row, _) if col == shape.1 => {
+                    let b = papergrid::Border {
+                        right: Some(color),
+                        ..Default::default()
+                    };
+
+                    ((row, col - 1), b)
+                }
+                Slot::Line(row, _) => {
+                    let b = papergrid::Border {
+                        left: Some(color),
+                        ..Default::default()
+                    };
+
+                    ((row, col), b)
                 }
+            };
+
+            cfg.set_border_color(pos, b);
+        }
+    }
+}
+
+/// A place of a char on a vertical line.
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+    /// An intersection with a horizontal line.
+    Intersection(usize),
+    /// A line of a row.
+    Line(usize, usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Side {
+    Start,
+    Center,
+    End,
+}
+
+fn text_position(
+    start: usize,
+    end: usize,
+    length: usize,
+    offset: Offset,
+    side: Option<Side>,
+) -> usize {
+    let space = (end - start).saturating_sub(length);
+    match side {
+        Some(Side::Start) => start,
+        Some(Side::Center) => start + space / 2,
+        Some(Side::End) => start + space,
+        None => match offset {
+            Offset::Begin(offset) => start + offset,
+            Offset::End(offset) => std::cmp::max(end.saturating_sub(offset), start),
+        },
+    }
+}
 
-                row
+/// Returns chars of a split line and a start position of each column.
+fn build_split_line(
+    cfg: &GridConfig,
+    row: usize,
+    widths: &[usize],
+    shape: (usize, usize),
+) -> (Vec<char>, Vec<usize>) {
+    let intersection = |col| cfg.get_intersection((row, col), shape).copied();
+
+    let mut line = Vec::new();
+    let mut starts = Vec::with_capacity(widths.len());
+    for (col, &width) in widths.iter().enumerate() {
+        if col == 0 && cfg.has_vertical(0, shape.1) {
+            line.push(intersection(0).unwrap_or(' '));
+        }
+
+        starts.push(line.len());
+
+        let main = cfg.get_horizontal((row, col), shape.0).copied();
+        for i in 0..width {
+            let c = main
+                .and_then(|_| cfg.lookup_overidden_horizontal((row, col), i, width))
+                .or(main)
+                .unwrap_or(' ');
+            line.push(c);
+        }
+
+        if cfg.has_vertical(col + 1, shape.1) {
+            line.push(intersection(col + 1).unwrap_or(' '));
+        }
+    }
+
+    (line, starts)
+}
+
+/// Merges texts by their positions into a single one, filling gaps by chars of a line.
+///
+/// Returns a start position of the merged text.
+fn compose_labels(line: &[char], labels: &[(usize, String)]) -> (usize, String) {
+    let begin = labels.iter().map(|(pos, _)| *pos).min().unwrap_or(0);
+    let end = labels
+        .iter()
+        .map(|(pos, text)| pos + string_width(text))
+        .max()
+        .unwrap_or(0);
+
+    let mut owners = vec![None; end.saturating_sub(begin)];
+    for (i, (pos, text)) in labels.iter().enumerate() {
+        let pos = pos - begin;
+        for owner in &mut owners[pos..pos + string_width(text)] {
+            *owner = Some(i);
+        }
+    }
+
+    let mut buf = String::new();
+    let mut i = 0;
+    while i < owners.len() {
+        let label = match owners[i] {
+            Some(label) => label,
+            None => {
+                buf.push(line.get(begin + i).copied().unwrap_or(' '));
+                i += 1;
+                continue;
             }
         };
 
-        table
-            .get_config_mut()
-            .override_split_line(row, self.text.as_ref(), self.offset.into());
+        let length = owners[i..]
+            .iter()
+            .take_while(|&&owner| owner == Some(label))
+            .count();
+
+        let (pos, text) = &labels[label];
+        let (_, rest) = spplit_str_at(text, begin + i - pos);
+        let (part, _) = spplit_str_at(&rest, length);
+        buf.push_str(&part);
+
+        i += length;
+    }
+
+    (begin, buf)
+}
+
+fn offset_start_pos(offset: papergrid::Offset, length: usize) -> usize {
+    match offset {
+        papergrid::Offset::Begin(o) => o,
+        papergrid::Offset::End(o) => length.saturating_sub(o),
     }
 }
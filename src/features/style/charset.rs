@@ -0,0 +1,237 @@
+//! This module contains [`Charset`] structure, used to adapt borders of a [`Table`]
+//! to the capabilities of a terminal.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{records::Records, Border, Borders};
+
+use crate::{Table, TableOption};
+
+/// Charset declares which characters a terminal is able to show.
+///
+/// Being set to a [`Table`] as [`Charset::Ascii`] it replaces box-drawing characters
+/// of all borders by their ASCII equivalents (`┼` by `+`, `─` by `-`, `│` by `|` and so on).
+/// It keeps a structure of borders, so custom horizontal and vertical lines,
+/// borders of particular cells and a [`BorderText`] are converted as well.
+///
+/// So it must be set after a style.
+///
+/// You can use [`Charset::from_env`] to choose a charset by locale variables.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{style::Charset, Style, Table};
+///
+/// let table = Table::new([("Hello", 1)])
+///     .with(Style::rounded())
+///     .with(Charset::Ascii)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+-------+-----+\n",
+///         "| &str  | i32 |\n",
+///         "+-------+-----+\n",
+///         "| Hello | 1   |\n",
+///         "+-------+-----+",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`BorderText`]: crate::BorderText
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Charset {
+    /// Only ASCII characters.
+    Ascii,
+    /// Any characters including box-drawing ones.
+    Unicode,
+}
+
+impl Charset {
+    /// Detects a charset by environment variables.
+    ///
+    /// See [`Charset::detect`].
+    pub fn from_env() -> Self {
+        Self::detect(|name| std::env::var(name).ok())
+    }
+
+    /// Detects a charset by variables returned from a given function.
+    ///
+    /// A locale is taken from a first not empty variable of `LC_ALL`, `LC_CTYPE` and `LANG`.
+    ///
+    /// - a locale with `UTF-8` encoding enables Unicode;
+    /// - any other locale (like `C` or `en_US.ISO-8859-1`) expects ASCII;
+    /// - if no locale is set Unicode is expected,
+    ///   except Windows where it's expected only inside Windows Terminal (`WT_SESSION`).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::style::Charset;
+    ///
+    /// let charset = Charset::detect(|name| match name {
+    ///     "LANG" => Some(String::from("C")),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(charset, Charset::Ascii);
+    /// ```
+    pub fn detect<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|&name| var(name))
+            .find(|value| !value.is_empty());
+
+        match locale {
+            Some(locale) => {
+                let locale = locale.to_ascii_lowercase();
+                if locale.contains("utf-8") || locale.contains("utf8") {
+                    Self::Unicode
+                } else {
+                    Self::Ascii
+                }
+            }
+            None if cfg!(windows) && var("WT_SESSION").is_none() => Self::Ascii,
+            None => Self::Unicode,
+        }
+    }
+
+    /// Maps a character to the one which can be shown with the charset.
+    ///
+    /// Only box-drawing characters are changed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::style::Charset;
+    ///
+    /// assert_eq!(Charset::Ascii.convert('╬'), '+');
+    /// assert_eq!(Charset::Ascii.convert('═'), '=');
+    /// assert_eq!(Charset::Ascii.convert('*'), '*');
+    /// assert_eq!(Charset::Unicode.convert('╬'), '╬');
+    /// ```
+    pub fn convert(self, c: char) -> char {
+        match self {
+            Self::Unicode => c,
+            Self::Ascii => to_ascii(c),
+        }
+    }
+}
+
+impl<R> TableOption<R> for Charset
+where
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if *self == Self::Unicode {
+            return;
+        }
+
+        let (count_rows, count_cols) = table.shape();
+        let shape = (count_rows, count_cols);
+
+        let cfg = table.get_config_mut();
+
+        let borders = convert_borders(cfg.get_borders());
+        cfg.set_borders(borders);
+
+        if let Some(&c) = cfg.get_global_border() {
+            cfg.set_global_border(to_ascii(c));
+        }
+
+        for row in 0..=count_rows {
+            if let Some(line) = cfg.get_horizontal_line(row) {
+                let line = papergrid::HorizontalLine {
+                    main: line.main.map(to_ascii),
+                    intersection: line.intersection.map(to_ascii),
+                    left: line.left.map(to_ascii),
+                    right: line.right.map(to_ascii),
+                };
+                cfg.set_horizontal_line(row, line);
+            }
+
+            if let Some((text, offset)) = cfg.remove_split_line_text(row) {
+                let text = text.chars().map(to_ascii).collect::<String>();
+                cfg.override_split_line(row, text, offset);
+            }
+        }
+
+        for col in 0..=count_cols {
+            if let Some(line) = cfg.get_vertical_line(col) {
+                let line = papergrid::VerticalLine {
+                    main: line.main.map(to_ascii),
+                    intersection: line.intersection.map(to_ascii),
+                    top: line.top.map(to_ascii),
+                    bottom: line.bottom.map(to_ascii),
+                };
+                cfg.set_vertical_line(col, line);
+            }
+        }
+
+        for row in 0..count_rows {
+            for col in 0..count_cols {
+                let border = cfg.get_border((row, col), shape);
+                let converted = convert_border(&border);
+                if converted != border {
+                    cfg.set_border((row, col), converted);
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn convert_borders(borders: &Borders<char>) -> Borders<char> {
+    Borders {
+        top: borders.top.map(to_ascii),
+        top_left: borders.top_left.map(to_ascii),
+        top_right: borders.top_right.map(to_ascii),
+        top_intersection: borders.top_intersection.map(to_ascii),
+        bottom: borders.bottom.map(to_ascii),
+        bottom_left: borders.bottom_left.map(to_ascii),
+        bottom_right: borders.bottom_right.map(to_ascii),
+        bottom_intersection: borders.bottom_intersection.map(to_ascii),
+        horizontal: borders.horizontal.map(to_ascii),
+        horizontal_left: borders.horizontal_left.map(to_ascii),
+        horizontal_right: borders.horizontal_right.map(to_ascii),
+        vertical: borders.vertical.map(to_ascii),
+        vertical_left: borders.vertical_left.map(to_ascii),
+        vertical_right: borders.vertical_right.map(to_ascii),
+        intersection: borders.intersection.map(to_ascii),
+    }
+}
+
+fn convert_border(border: &Border<char>) -> Border<char> {
+    Border {
+        top: border.top.map(to_ascii),
+        bottom: border.bottom.map(to_ascii),
+        left: border.left.map(to_ascii),
+        right: border.right.map(to_ascii),
+        left_top_corner: border.left_top_corner.map(to_ascii),
+        left_bottom_corner: border.left_bottom_corner.map(to_ascii),
+        right_top_corner: border.right_top_corner.map(to_ascii),
+        right_bottom_corner: border.right_bottom_corner.map(to_ascii),
+    }
+}
+
+pub(crate) fn to_ascii(c: char) -> char {
+    match c {
+        '\u{2500}' | '\u{2501}' | '\u{2504}' | '\u{2505}' | '\u{2508}' | '\u{2509}'
+        | '\u{254C}' | '\u{254D}' | '\u{2574}' | '\u{2576}' | '\u{2578}' | '\u{257A}'
+        | '\u{257C}' | '\u{257E}' => '-',
+        '\u{2502}' | '\u{2503}' | '\u{2506}' | '\u{2507}' | '\u{250A}' | '\u{250B}'
+        | '\u{254E}' | '\u{254F}' | '\u{2551}' | '\u{2575}' | '\u{2577}' | '\u{2579}'
+        | '\u{257B}' | '\u{257D}' | '\u{257F}' => '|',
+        '\u{2550}' => '=',
+        '\u{250C}'..='\u{254B}' | '\u{2552}'..='\u{2570}' => '+',
+        '\u{2571}' => '/',
+        '\u{2572}' => '\\',
+        '\u{2573}' => 'X',
+        c => c,
+    }
+}
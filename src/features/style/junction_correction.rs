@@ -0,0 +1,334 @@
+//! This module contains [`StyleCorrectJunction`] structure, which picks box-drawing intersection symbols
+//! which are consistent with weights of lines meeting at them.
+
+use papergrid::{records::Records, Border, GridConfig, Position};
+
+use crate::{Table, TableOption};
+
+use Weight::{Double as D, Heavy as H, Light as L, None as N};
+
+/// A correctness function of style for [`Table`] which mixes light, heavy and double lines.
+///
+/// It replaces a box-drawing character on each intersection by the one
+/// which matches the lines meeting there,
+/// like `╞` for a double horizontal line crossing a light left border.
+///
+/// It considers [`Span`]s as well, so a line which is hidden by a span doesn't make an intersection.
+///
+/// If a line is not a box-drawing character, or there's no glyph for a combination of weights,
+/// the intersection is left intact.
+///
+/// See [`Style::correct_junctions`].
+///
+/// [`Table`]: crate::Table
+/// [`Span`]: crate::Span
+/// [`Style::correct_junctions`]: crate::Style::correct_junctions
+#[derive(Debug)]
+pub struct StyleCorrectJunction;
+
+impl<R> TableOption<R> for StyleCorrectJunction
+where
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let shape = table.shape();
+        if shape.0 == 0 || shape.1 == 0 {
+            return;
+        }
+
+        let mut junctions = Vec::new();
+        let cfg = table.get_config();
+        for row in 0..=shape.0 {
+            for col in 0..=shape.1 {
+                let pos = (row, col);
+                let c = match cfg.get_intersection(pos, shape) {
+                    Some(&c) => c,
+                    None => continue,
+                };
+
+                if get_arms(c).is_none() {
+                    continue;
+                }
+
+                let arms = match collect_arms(cfg, pos, shape) {
+                    Some(arms) => arms,
+                    None => continue,
+                };
+
+                if arms == [Weight::None; 4] || get_arms(c) == Some(arms) {
+                    continue;
+                }
+
+                if let Some(junction) = find_junction(arms) {
+                    junctions.push((pos, junction));
+                }
+            }
+        }
+
+        let cfg = table.get_config_mut();
+        for (pos, c) in junctions {
+            let (pos, border) = intersection_border(pos, c, shape);
+            cfg.set_border(pos, border);
+        }
+    }
+}
+
+/// Returns a border of an adjacent cell which sets an intersection.
+pub(crate) fn intersection_border<T>(
+    (row, col): Position,
+    c: T,
+    shape: (usize, usize),
+) -> (Position, Border<T>)
+where
+    T: Default,
+{
+    let mut border = Border::default();
+    let pos = match (row < shape.0, col < shape.1) {
+        (true, true) => {
+            border.left_top_corner = Some(c);
+            (row, col)
+        }
+        (true, false) => {
+            border.right_top_corner = Some(c);
+            (row, col - 1)
+        }
+        (false, true) => {
+            border.left_bottom_corner = Some(c);
+            (row - 1, col)
+        }
+        (false, false) => {
+            border.right_bottom_corner = Some(c);
+            (row - 1, col - 1)
+        }
+    };
+
+    (pos, border)
+}
+
+/// A weight of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Weight {
+    None,
+    Light,
+    Heavy,
+    Double,
+}
+
+/// Collects weights of lines around an intersection (top, right, bottom and left ones).
+///
+/// It returns [`None`] if any of the lines is not a box-drawing character.
+fn collect_arms(cfg: &GridConfig, pos: Position, shape: (usize, usize)) -> Option<[Weight; 4]> {
+    let (row, col) = pos;
+    let (count_rows, count_cols) = shape;
+
+    let vertical = |row: usize| {
+        let is_hidden = col < count_cols
+            && (cfg.is_cell_covered_by_column_span((row, col), shape)
+                || cfg.is_cell_covered_by_both_spans((row, col), shape));
+        if is_hidden || !cfg.has_vertical(col, count_cols) {
+            return Some(Weight::None);
+        }
+
+        match cfg.get_vertical((row, col), count_cols) {
+            Some(&c) => get_arms(c).map(|arms| arms[0]),
+            None => Some(Weight::None),
+        }
+    };
+
+    let horizontal = |col: usize| {
+        let is_hidden = row < count_rows
+            && (cfg.is_cell_covered_by_row_span((row, col), shape)
+                || cfg.is_cell_covered_by_both_spans((row, col), shape));
+        if is_hidden || !cfg.has_horizontal(row, count_rows) {
+            return Some(Weight::None);
+        }
+
+        match cfg.get_horizontal((row, col), count_rows) {
+            Some(&c) => get_arms(c).map(|arms| arms[1]),
+            None => Some(Weight::None),
+        }
+    };
+
+    let top = if row > 0 {
+        vertical(row - 1)?
+    } else {
+        Weight::None
+    };
+    let bottom = if row < count_rows {
+        vertical(row)?
+    } else {
+        Weight::None
+    };
+    let left = if col > 0 {
+        horizontal(col - 1)?
+    } else {
+        Weight::None
+    };
+    let right = if col < count_cols {
+        horizontal(col)?
+    } else {
+        Weight::None
+    };
+
+    Some([top, right, bottom, left])
+}
+
+fn get_arms(c: char) -> Option<[Weight; 4]> {
+    GLYPHS
+        .iter()
+        .find(|(glyph, _)| *glyph == c)
+        .map(|&(_, arms)| arms)
+}
+
+/// Finds a glyph for given arms, using light lines instead of heavy ones if there's no exact one.
+fn find_junction(arms: [Weight; 4]) -> Option<char> {
+    let find = |arms: [Weight; 4]| {
+        GLYPHS
+            .iter()
+            .find(|&&(_, glyph_arms)| glyph_arms == arms)
+            .map(|&(c, _)| c)
+    };
+
+    find(arms).or_else(|| {
+        let mut arms = arms;
+        for weight in &mut arms {
+            if *weight == Weight::Heavy {
+                *weight = Weight::Light;
+            }
+        }
+
+        find(arms)
+    })
+}
+
+/// Box-drawing characters with weights of their top, right, bottom and left lines.
+///
+/// Dashed lines and arcs go last so they're never picked in place of solid ones.
+#[rustfmt::skip]
+const GLYPHS: [(char, [Weight; 4]); 125] = [
+    ('─', [N, L, N, L]),
+    ('━', [N, H, N, H]),
+    ('│', [L, N, L, N]),
+    ('┃', [H, N, H, N]),
+    ('┌', [N, L, L, N]),
+    ('┍', [N, H, L, N]),
+    ('┎', [N, L, H, N]),
+    ('┏', [N, H, H, N]),
+    ('┐', [N, N, L, L]),
+    ('┑', [N, N, L, H]),
+    ('┒', [N, N, H, L]),
+    ('┓', [N, N, H, H]),
+    ('└', [L, L, N, N]),
+    ('┕', [L, H, N, N]),
+    ('┖', [H, L, N, N]),
+    ('┗', [H, H, N, N]),
+    ('┘', [L, N, N, L]),
+    ('┙', [L, N, N, H]),
+    ('┚', [H, N, N, L]),
+    ('┛', [H, N, N, H]),
+    ('├', [L, L, L, N]),
+    ('┝', [L, H, L, N]),
+    ('┞', [H, L, L, N]),
+    ('┟', [L, L, H, N]),
+    ('┠', [H, L, H, N]),
+    ('┡', [H, H, L, N]),
+    ('┢', [L, H, H, N]),
+    ('┣', [H, H, H, N]),
+    ('┤', [L, N, L, L]),
+    ('┥', [L, N, L, H]),
+    ('┦', [H, N, L, L]),
+    ('┧', [L, N, H, L]),
+    ('┨', [H, N, H, L]),
+    ('┩', [H, N, L, H]),
+    ('┪', [L, N, H, H]),
+    ('┫', [H, N, H, H]),
+    ('┬', [N, L, L, L]),
+    ('┭', [N, L, L, H]),
+    ('┮', [N, H, L, L]),
+    ('┯', [N, H, L, H]),
+    ('┰', [N, L, H, L]),
+    ('┱', [N, L, H, H]),
+    ('┲', [N, H, H, L]),
+    ('┳', [N, H, H, H]),
+    ('┴', [L, L, N, L]),
+    ('┵', [L, L, N, H]),
+    ('┶', [L, H, N, L]),
+    ('┷', [L, H, N, H]),
+    ('┸', [H, L, N, L]),
+    ('┹', [H, L, N, H]),
+    ('┺', [H, H, N, L]),
+    ('┻', [H, H, N, H]),
+    ('┼', [L, L, L, L]),
+    ('┽', [L, L, L, H]),
+    ('┾', [L, H, L, L]),
+    ('┿', [L, H, L, H]),
+    ('╀', [H, L, L, L]),
+    ('╁', [L, L, H, L]),
+    ('╂', [H, L, H, L]),
+    ('╃', [H, L, L, H]),
+    ('╄', [H, H, L, L]),
+    ('╅', [L, L, H, H]),
+    ('╆', [L, H, H, L]),
+    ('╇', [H, H, L, H]),
+    ('╈', [L, H, H, H]),
+    ('╉', [H, L, H, H]),
+    ('╊', [H, H, H, L]),
+    ('╋', [H, H, H, H]),
+    ('═', [N, D, N, D]),
+    ('║', [D, N, D, N]),
+    ('╒', [N, D, L, N]),
+    ('╓', [N, L, D, N]),
+    ('╔', [N, D, D, N]),
+    ('╕', [N, N, L, D]),
+    ('╖', [N, N, D, L]),
+    ('╗', [N, N, D, D]),
+    ('╘', [L, D, N, N]),
+    ('╙', [D, L, N, N]),
+    ('╚', [D, D, N, N]),
+    ('╛', [L, N, N, D]),
+    ('╜', [D, N, N, L]),
+    ('╝', [D, N, N, D]),
+    ('╞', [L, D, L, N]),
+    ('╟', [D, L, D, N]),
+    ('╠', [D, D, D, N]),
+    ('╡', [L, N, L, D]),
+    ('╢', [D, N, D, L]),
+    ('╣', [D, N, D, D]),
+    ('╤', [N, D, L, D]),
+    ('╥', [N, L, D, L]),
+    ('╦', [N, D, D, D]),
+    ('╧', [L, D, N, D]),
+    ('╨', [D, L, N, L]),
+    ('╩', [D, D, N, D]),
+    ('╪', [L, D, L, D]),
+    ('╫', [D, L, D, L]),
+    ('╬', [D, D, D, D]),
+    ('╴', [N, N, N, L]),
+    ('╵', [L, N, N, N]),
+    ('╶', [N, L, N, N]),
+    ('╷', [N, N, L, N]),
+    ('╸', [N, N, N, H]),
+    ('╹', [H, N, N, N]),
+    ('╺', [N, H, N, N]),
+    ('╻', [N, N, H, N]),
+    ('╼', [N, H, N, L]),
+    ('╽', [L, N, H, N]),
+    ('╾', [N, L, N, H]),
+    ('╿', [H, N, L, N]),
+    ('┄', [N, L, N, L]),
+    ('┅', [N, H, N, H]),
+    ('┆', [L, N, L, N]),
+    ('┇', [H, N, H, N]),
+    ('┈', [N, L, N, L]),
+    ('┉', [N, H, N, H]),
+    ('┊', [L, N, L, N]),
+    ('┋', [H, N, H, N]),
+    ('╌', [N, L, N, L]),
+    ('╍', [N, H, N, H]),
+    ('╎', [L, N, L, N]),
+    ('╏', [H, N, H, N]),
+    ('╭', [N, L, L, N]),
+    ('╮', [N, N, L, L]),
+    ('╯', [L, N, N, L]),
+    ('╰', [L, L, N, N]),
+];
@@ -5,13 +5,17 @@
 mod border;
 mod border_char;
 mod border_text;
+mod charset;
 mod horizontal_line;
+mod junction_correction;
 mod line;
 mod offset;
 mod raw_style;
+mod separator;
 mod span_border_correction;
 #[allow(clippy::module_inception)]
 mod style;
+mod themes;
 mod vertical_line;
 
 #[cfg(feature = "color")]
@@ -22,9 +26,11 @@ mod raw_style_colored;
 mod symbol;
 
 pub use self::{
-    border::Border, border_char::BorderChar, border_text::BorderText,
-    horizontal_line::HorizontalLine, line::Line, offset::Offset, raw_style::RawStyle,
-    span_border_correction::StyleCorrectSpan, style::Style, vertical_line::VerticalLine,
+    border::Border, border_char::BorderChar, border_text::BorderText, charset::Charset,
+    horizontal_line::HorizontalLine, junction_correction::StyleCorrectJunction, line::Line,
+    offset::Offset, raw_style::RawStyle, separator::Separator,
+    span_border_correction::StyleCorrectSpan, style::Style, themes::Themes,
+    vertical_line::VerticalLine,
 };
 
 #[cfg(feature = "color")]
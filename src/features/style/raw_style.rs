@@ -6,7 +6,10 @@ use std::collections::HashMap;
 use papergrid::{records::Records, Borders};
 
 use crate::{
-    style::{HorizontalLine, Line, VerticalLine},
+    style::{
+        charset::{convert_borders, to_ascii},
+        HorizontalLine, Line, VerticalLine,
+    },
     Border, Style, Table, TableOption,
 };
 
@@ -187,6 +190,53 @@ impl RawStyle {
         self
     }
 
+    /// Returns a copy of the style where box-drawing characters,
+    /// including ones of horizontal and vertical lines, are replaced by ASCII equivalents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{style::{RawStyle, Style}, Table};
+    ///
+    /// let style = RawStyle::from(Style::extended()).to_ascii();
+    ///
+    /// let table = Table::new([("Hello", 1)]).with(style).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+=======+=====+\n",
+    ///         "| &str  | i32 |\n",
+    ///         "+=======+=====+\n",
+    ///         "| Hello | 1   |\n",
+    ///         "+=======+=====+",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_ascii(&self) -> Self {
+        let convert_lines = |lines: &HashMap<usize, Line>| {
+            lines
+                .iter()
+                .map(|(&i, line)| {
+                    let line = Line::new(
+                        line.main.map(to_ascii),
+                        line.intersection.map(to_ascii),
+                        line.connector1.map(to_ascii),
+                        line.connector2.map(to_ascii),
+                    );
+
+                    (i, line)
+                })
+                .collect()
+        };
+
+        Self {
+            borders: convert_borders(&self.borders),
+            horizontals: convert_lines(&self.horizontals),
+            verticals: convert_lines(&self.verticals),
+        }
+    }
+
     /// Get a left char.
     pub fn get_left(&self) -> Option<char> {
         self.borders.vertical_left
@@ -359,3 +409,230 @@ where
         }
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for RawStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        StyleDef::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for RawStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let def = StyleDef::deserialize(deserializer)?;
+        if def.colors.is_some() {
+            return Err(serde::de::Error::custom(
+                "RawStyle can't have colors, use RawStyleColored instead",
+            ));
+        }
+
+        Ok(Self::from(def))
+    }
+}
+
+/// A serialized form of a [`RawStyle`].
+///
+/// Lines are stored as lists instead of maps,
+/// because some formats (like TOML) don't support not string keys.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct StyleDef {
+    #[serde(flatten)]
+    pub(crate) borders: BordersDef<char>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) horizontals: Vec<HorizontalLineDef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) verticals: Vec<VerticalLineDef>,
+    #[cfg(feature = "color")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) colors: Option<BordersDef<super::raw_style_colored::ColorDef>>,
+    /// Colors are only parsed to be rejected, as they can't be used without a `color` feature.
+    #[cfg(not(feature = "color"))]
+    #[serde(default, skip_serializing)]
+    pub(crate) colors: Option<serde::de::IgnoredAny>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "T: serde::Deserialize<'de>"))]
+pub(crate) struct BordersDef<T> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    top: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bottom: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    left: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    right: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    horizontal: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vertical: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    top_left: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    top_right: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bottom_left: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bottom_right: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    top_intersection: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bottom_intersection: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    left_intersection: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    right_intersection: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    intersection: Option<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> BordersDef<T> {
+    pub(crate) fn from_borders<U>(borders: &Borders<U>, f: impl Fn(&U) -> T) -> Self {
+        let f = |c: &Option<U>| c.as_ref().map(&f);
+
+        Self {
+            top: f(&borders.top),
+            bottom: f(&borders.bottom),
+            left: f(&borders.vertical_left),
+            right: f(&borders.vertical_right),
+            horizontal: f(&borders.horizontal),
+            vertical: f(&borders.vertical),
+            top_left: f(&borders.top_left),
+            top_right: f(&borders.top_right),
+            bottom_left: f(&borders.bottom_left),
+            bottom_right: f(&borders.bottom_right),
+            top_intersection: f(&borders.top_intersection),
+            bottom_intersection: f(&borders.bottom_intersection),
+            left_intersection: f(&borders.horizontal_left),
+            right_intersection: f(&borders.horizontal_right),
+            intersection: f(&borders.intersection),
+        }
+    }
+
+    pub(crate) fn into_borders<U>(self, f: impl Fn(T) -> U) -> Borders<U> {
+        Borders {
+            top: self.top.map(&f),
+            bottom: self.bottom.map(&f),
+            vertical_left: self.left.map(&f),
+            vertical_right: self.right.map(&f),
+            horizontal: self.horizontal.map(&f),
+            vertical: self.vertical.map(&f),
+            top_left: self.top_left.map(&f),
+            top_right: self.top_right.map(&f),
+            bottom_left: self.bottom_left.map(&f),
+            bottom_right: self.bottom_right.map(&f),
+            top_intersection: self.top_intersection.map(&f),
+            bottom_intersection: self.bottom_intersection.map(&f),
+            horizontal_left: self.left_intersection.map(&f),
+            horizontal_right: self.right_intersection.map(&f),
+            intersection: self.intersection.map(&f),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct HorizontalLineDef {
+    index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    main: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    intersection: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    left: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    right: Option<char>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct VerticalLineDef {
+    index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    main: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    intersection: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    top: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bottom: Option<char>,
+}
+
+#[cfg(feature = "serde")]
+impl From<&RawStyle> for StyleDef {
+    fn from(style: &RawStyle) -> Self {
+        let mut horizontals = style
+            .horizontals
+            .iter()
+            .map(|(&index, line)| HorizontalLineDef {
+                index,
+                main: line.main,
+                intersection: line.intersection,
+                left: line.connector1,
+                right: line.connector2,
+            })
+            .collect::<Vec<_>>();
+        horizontals.sort_by_key(|line| line.index);
+
+        let mut verticals = style
+            .verticals
+            .iter()
+            .map(|(&index, line)| VerticalLineDef {
+                index,
+                main: line.main,
+                intersection: line.intersection,
+                top: line.connector1,
+                bottom: line.connector2,
+            })
+            .collect::<Vec<_>>();
+        verticals.sort_by_key(|line| line.index);
+
+        Self {
+            borders: BordersDef::from_borders(&style.borders, |&c| c),
+            horizontals,
+            verticals,
+            colors: None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<StyleDef> for RawStyle {
+    fn from(def: StyleDef) -> Self {
+        let horizontals = def
+            .horizontals
+            .into_iter()
+            .map(|l| {
+                let line = Line::new(l.main, l.intersection, l.left, l.right);
+                (l.index, line)
+            })
+            .collect();
+
+        let verticals = def
+            .verticals
+            .into_iter()
+            .map(|l| {
+                let line = Line::new(l.main, l.intersection, l.top, l.bottom);
+                (l.index, line)
+            })
+            .collect();
+
+        Self {
+            borders: def.borders.into_borders(|c| c),
+            horizontals,
+            verticals,
+        }
+    }
+}
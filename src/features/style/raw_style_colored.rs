@@ -11,6 +11,9 @@ use crate::{
     Table, TableOption,
 };
 
+#[cfg(feature = "serde")]
+use super::raw_style::{BordersDef, StyleDef};
+
 /// A colored [`RawStyle`] versions.
 #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
 #[derive(Debug, Clone)]
@@ -206,3 +209,49 @@ impl From<RawStyle> for RawStyleColored {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "color", feature = "serde"))))]
+impl serde::Serialize for RawStyleColored {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut def = StyleDef::from(&self.style);
+        def.colors = Some(BordersDef::from_borders(&self.colors, |color| ColorDef {
+            prefix: color.get_prefix().to_owned(),
+            suffix: color.get_suffix().to_owned(),
+        }));
+
+        def.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "color", feature = "serde"))))]
+impl<'de> serde::Deserialize<'de> for RawStyleColored {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut def = StyleDef::deserialize(deserializer)?;
+        let colors = match def.colors.take() {
+            Some(colors) => colors
+                .into_borders(|color| AnsiColor::new(color.prefix.into(), color.suffix.into())),
+            None => Borders::default(),
+        };
+
+        Ok(Self {
+            style: RawStyle::from(def),
+            colors,
+        })
+    }
+}
+
+/// A serialized form of a border color, as a pair of ANSI sequences.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct ColorDef {
+    prefix: String,
+    suffix: String,
+}
@@ -0,0 +1,250 @@
+//! This module contains [`Separator`] structure, which sets border lines by rows and columns.
+
+use papergrid::{records::Records, Border, GridConfig, Position};
+
+use crate::{locator::Locator, style::Line, Table, TableOption};
+
+#[cfg(feature = "color")]
+use crate::color::Color;
+
+/// [`Separator`] sets a line above each located row or to the left of each located column.
+///
+/// A line which is not present in a style is added.
+/// Its intersections and ends are set only where crossing lines are present,
+/// so a separator doesn't change a layout of a table otherwise.
+/// Not set characters of a [`Line`] are left intact.
+///
+/// A separator is set as borders of cells,
+/// so same as with [`Border`] and [`BorderColored`] the latest applied option wins,
+/// while a [`Style`] set afterwards resets all separators.
+/// You may want to use [`Style::correct_junctions`] after all separators are set.
+///
+/// ```
+/// use tabled::{object::{Columns, Rows}, style::{Line, Separator}, Style, Table};
+///
+/// let data = [("Apple", 3, 2), ("Pear", 4, 1), ("Total", 7, 3)];
+///
+/// let table = Table::new(data)
+///     .with(Style::rounded())
+///     .with(Separator::rows(Rows::last(), Line::full('━', '┿', '┝', '┥')))
+///     .with(Separator::columns(Columns::new(2..), Line::new(Some('┆'), None, None, None)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "╭───────┬─────┬─────╮\n",
+///         "│ &str  │ i32 ┆ i32 │\n",
+///         "├───────┼─────┼─────┤\n",
+///         "│ Apple │ 3   ┆ 2   │\n",
+///         "│ Pear  │ 4   ┆ 1   │\n",
+///         "┝━━━━━━━┿━━━━━┿━━━━━┥\n",
+///         "│ Total │ 7   ┆ 3   │\n",
+///         "╰───────┴─────┴─────╯",
+///     )
+/// );
+/// ```
+///
+/// [`Border`]: crate::Border
+/// [`BorderColored`]: crate::style::BorderColored
+/// [`Style`]: crate::Style
+/// [`Style::correct_junctions`]: crate::Style::correct_junctions
+#[derive(Debug, Clone)]
+pub struct Separator<L> {
+    locator: L,
+    line: Line,
+    vertical: bool,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
+}
+
+impl<L> Separator<L> {
+    /// Sets a horizontal line above each located row.
+    ///
+    /// A line of a last row + 1 index is a bottom line.
+    ///
+    /// A line is configured as a [`HorizontalLine`]:
+    /// [`Line`] connectors are used as left and right ends.
+    ///
+    /// [`HorizontalLine`]: crate::style::HorizontalLine
+    pub fn rows(locator: L, line: Line) -> Self
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Self::new(locator, line, false)
+    }
+
+    /// Sets a vertical line to the left of each located column.
+    ///
+    /// A line of a last column + 1 index is a right line.
+    ///
+    /// A line is configured as a [`VerticalLine`]:
+    /// [`Line`] connectors are used as top and bottom ends.
+    ///
+    /// [`VerticalLine`]: crate::style::VerticalLine
+    pub fn columns(locator: L, line: Line) -> Self
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Self::new(locator, line, true)
+    }
+
+    fn new(locator: L, line: Line, vertical: bool) -> Self {
+        Self {
+            locator,
+            line,
+            vertical,
+            #[cfg(feature = "color")]
+            color: None,
+        }
+    }
+
+    /// Sets a color of a line including its intersections.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl<L, R> TableOption<R> for Separator<L>
+where
+    L: Locator<Coordinate = usize>,
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let shape = table.shape();
+        if shape.0 == 0 || shape.1 == 0 {
+            return;
+        }
+
+        let (count_lines, count_cells) = match self.vertical {
+            true => (shape.1, shape.0),
+            false => (shape.0, shape.1),
+        };
+
+        let lines = self
+            .locator
+            .locate(table.get_records())
+            .into_iter()
+            .filter(|&line| line <= count_lines)
+            .collect::<Vec<_>>();
+
+        let cfg = table.get_config_mut();
+        for line in lines {
+            for i in 0..count_cells {
+                let (pos, border) = match self.vertical {
+                    true => vertical_border(cfg, &self.line, line, i, shape),
+                    false => horizontal_border(cfg, &self.line, line, i, shape),
+                };
+
+                #[cfg(feature = "color")]
+                if let Some(color) = &self.color {
+                    let color = papergrid::AnsiColor::from(color.clone());
+                    cfg.set_border_color(pos, map_border(&border, &color));
+                }
+
+                cfg.set_border(pos, border);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn horizontal_border(
+    cfg: &GridConfig,
+    line: &Line,
+    row: usize,
+    col: usize,
+    shape: (usize, usize),
+) -> (Position, Border) {
+    let (count_rows, count_cols) = shape;
+    let is_bottom = row == count_rows;
+
+    let left = if col == 0 {
+        line.connector1
+    } else {
+        line.intersection
+    };
+    let left = left.filter(|_| cfg.has_vertical(col, count_cols));
+
+    let right = if col + 1 == count_cols {
+        line.connector2
+    } else {
+        None
+    };
+    let right = right.filter(|_| cfg.has_vertical(count_cols, count_cols));
+
+    let mut border = Border::default();
+    if is_bottom {
+        border.bottom = line.main;
+        border.left_bottom_corner = left;
+        border.right_bottom_corner = right;
+        ((row - 1, col), border)
+    } else {
+        border.top = line.main;
+        border.left_top_corner = left;
+        border.right_top_corner = right;
+        ((row, col), border)
+    }
+}
+
+fn vertical_border(
+    cfg: &GridConfig,
+    line: &Line,
+    col: usize,
+    row: usize,
+    shape: (usize, usize),
+) -> (Position, Border) {
+    let (count_rows, count_cols) = shape;
+    let is_right = col == count_cols;
+
+    let top = if row == 0 {
+        line.connector1
+    } else {
+        line.intersection
+    };
+    let top = top.filter(|_| cfg.has_horizontal(row, count_rows));
+
+    let bottom = if row + 1 == count_rows {
+        line.connector2
+    } else {
+        None
+    };
+    let bottom = bottom.filter(|_| cfg.has_horizontal(count_rows, count_rows));
+
+    let mut border = Border::default();
+    if is_right {
+        border.right = line.main;
+        border.right_top_corner = top;
+        border.right_bottom_corner = bottom;
+        ((row, col - 1), border)
+    } else {
+        border.left = line.main;
+        border.left_top_corner = top;
+        border.left_bottom_corner = bottom;
+        ((row, col), border)
+    }
+}
+
+#[cfg(feature = "color")]
+fn map_border(
+    border: &Border,
+    color: &papergrid::AnsiColor<'static>,
+) -> Border<papergrid::AnsiColor<'static>> {
+    let map = |c: Option<char>| c.map(|_| color.clone());
+
+    Border {
+        top: map(border.top),
+        bottom: map(border.bottom),
+        left: map(border.left),
+        right: map(border.right),
+        left_top_corner: map(border.left_top_corner),
+        left_bottom_corner: map(border.left_bottom_corner),
+        right_top_corner: map(border.right_top_corner),
+        right_bottom_corner: map(border.right_bottom_corner),
+    }
+}
@@ -95,7 +95,10 @@ use std::marker::PhantomData;
 
 use papergrid::{records::Records, Borders};
 
-use crate::{style::StyleCorrectSpan, Border, Table, TableOption};
+use crate::{
+    style::{StyleCorrectJunction, StyleCorrectSpan},
+    Border, Table, TableOption,
+};
 
 use super::{HorizontalLine, Line, VerticalLine};
 
@@ -546,6 +549,53 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn correct_spans() -> StyleCorrectSpan {
         StyleCorrectSpan
     }
+
+    /// Returns a [`StyleCorrectJunction`] which picks intersection symbols
+    /// consistent with lines of different weights (light, heavy and double) meeting at them.
+    ///
+    /// It must be called after all borders and spans are set.
+    ///
+    /// ```
+    /// use tabled::{style::HorizontalLine, TableIteratorExt, Style};
+    ///
+    /// let data = vec![("09", "June"), ("10", "July")];
+    ///
+    /// let style = Style::modern()
+    ///     .off_horizontal()
+    ///     .horizontals([HorizontalLine::new(1, Style::extended().get_horizontal())]);
+    ///
+    /// let mut table = data.table();
+    /// table.with(style);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "┌──────┬──────┐\n",
+    ///         "│ &str │ &str │\n",
+    ///         "╠══════╬══════╣\n",
+    ///         "│ 09   │ June │\n",
+    ///         "│ 10   │ July │\n",
+    ///         "└──────┴──────┘",
+    ///     )
+    /// );
+    ///
+    /// table.with(Style::correct_junctions());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "┌──────┬──────┐\n",
+    ///         "│ &str │ &str │\n",
+    ///         "╞══════╪══════╡\n",
+    ///         "│ 09   │ June │\n",
+    ///         "│ 10   │ July │\n",
+    ///         "└──────┴──────┘",
+    ///     )
+    /// );
+    /// ```
+    pub const fn correct_junctions() -> StyleCorrectJunction {
+        StyleCorrectJunction
+    }
 }
 
 impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
@@ -0,0 +1,147 @@
+//! This module contains [`Themes`] structure, a registry of named [`RawStyle`]s.
+
+use std::{collections::BTreeMap, iter::FromIterator};
+
+use crate::style::{RawStyle, Style};
+
+/// A registry of named [`RawStyle`]s, which allows to choose a style at runtime.
+///
+/// [`Themes::new`] contains all [`Style`] presets named as their constructors
+/// (`"ascii"`, `"modern"`, `"rounded"`, `"re_structured_text"` and so on).
+///
+/// With a `serde` feature [`RawStyle`] (and `RawStyleColored` with a `color` feature)
+/// can be serialized, so custom themes can be loaded from a config file
+/// in any format supported by `serde` (like TOML or JSON).
+/// [`Themes`] itself is (de)serialized as a map of styles by names.
+/// Colors are not kept in [`RawStyle`], so a style with `colors` fails to be deserialized.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{style::Themes, Table};
+///
+/// let themes = Themes::new();
+/// let style = themes.get("psql").unwrap();
+///
+/// let table = Table::new([("Hello", 1)]).with(style).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str  | i32 \n",
+///         "-------+-----\n",
+///         " Hello | 1   ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Themes {
+    themes: BTreeMap<String, RawStyle>,
+}
+
+impl Themes {
+    /// Creates a registry with all [`Style`] presets.
+    pub fn new() -> Self {
+        let presets = [
+            ("empty", RawStyle::from(Style::empty())),
+            ("blank", RawStyle::from(Style::blank())),
+            ("ascii", RawStyle::from(Style::ascii())),
+            ("psql", RawStyle::from(Style::psql())),
+            ("markdown", RawStyle::from(Style::markdown())),
+            ("modern", RawStyle::from(Style::modern())),
+            ("sharp", RawStyle::from(Style::sharp())),
+            ("rounded", RawStyle::from(Style::rounded())),
+            ("extended", RawStyle::from(Style::extended())),
+            ("dots", RawStyle::from(Style::dots())),
+            (
+                "re_structured_text",
+                RawStyle::from(Style::re_structured_text()),
+            ),
+            ("ascii_rounded", RawStyle::from(Style::ascii_rounded())),
+        ];
+
+        presets.iter().cloned().collect()
+    }
+
+    /// Creates a registry without any styles.
+    pub fn empty() -> Self {
+        Self {
+            themes: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a style by a name, returning a style which was registered by the name before.
+    pub fn insert<S>(&mut self, name: S, style: RawStyle) -> Option<RawStyle>
+    where
+        S: Into<String>,
+    {
+        self.themes.insert(name.into(), style)
+    }
+
+    /// Removes a style by a name.
+    pub fn remove(&mut self, name: &str) -> Option<RawStyle> {
+        self.themes.remove(name)
+    }
+
+    /// Returns a style by a name.
+    pub fn get(&self, name: &str) -> Option<&RawStyle> {
+        self.themes.get(name)
+    }
+
+    /// Returns names of all registered styles in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.keys().map(String::as_str)
+    }
+
+    /// Returns all registered styles in alphabetical order of their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RawStyle)> {
+        self.themes
+            .iter()
+            .map(|(name, style)| (name.as_str(), style))
+    }
+}
+
+impl Default for Themes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntoIterator for Themes {
+    type Item = (String, RawStyle);
+    type IntoIter = std::collections::btree_map::IntoIter<String, RawStyle>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.themes.into_iter()
+    }
+}
+
+impl<S> Extend<(S, RawStyle)> for Themes
+where
+    S: Into<String>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (S, RawStyle)>,
+    {
+        for (name, style) in iter {
+            self.insert(name, style);
+        }
+    }
+}
+
+impl<S> FromIterator<(S, RawStyle)> for Themes
+where
+    S: Into<String>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (S, RawStyle)>,
+    {
+        let mut themes = Self::empty();
+        themes.extend(iter);
+        themes
+    }
+}
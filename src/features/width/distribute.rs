@@ -0,0 +1,369 @@
+//! This module contains [`Distribute`] structure, used to spread a [`Table`] width among its columns
+//! according to a set of [`Flex`] constraints.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{empty::EmptyRecords, Records, RecordsMut};
+
+use crate::{measurement::Measurement, CellOption, Table, TableOption, Width};
+
+use super::{get_table_widths, get_table_widths_with_total, truncate::get_decrease_cell_list};
+
+/// A set of constraints of a single column used by [`Distribute`].
+///
+/// The width values are the same as used by [`Width::list`],
+/// so they do include a padding but do not include vertical lines.
+///
+/// It's much like a CSS flexbox item:
+///
+/// - a weight (like a `fr` unit) determines the share of space which a column gets or gives away;
+/// - `min` and `max` limit the column width;
+/// - a column can be marked as never-shrink or never-grow, or fixed which means both.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::width::Flex;
+///
+/// let name = Flex::new().weight(2).min(10);
+/// let id = Flex::fixed();
+/// let comment = Flex::new().max(40).no_shrink();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flex {
+    weight: usize,
+    min: usize,
+    max: Option<usize>,
+    shrink: bool,
+    grow: bool,
+}
+
+impl Flex {
+    /// Creates a flexible column with a weight 1 and no limits.
+    pub fn new() -> Self {
+        Self {
+            weight: 1,
+            min: 0,
+            max: None,
+            shrink: true,
+            grow: true,
+        }
+    }
+
+    /// Creates a column which will neither shrink nor grow.
+    pub fn fixed() -> Self {
+        Self::new().no_shrink().no_grow()
+    }
+
+    /// Sets a weight of the column.
+    ///
+    /// A column with weight 2 gets (or loses) twice as much width as a column with weight 1.
+    /// A column with weight 0 is never changed unless it violates `min` or `max`.
+    pub fn weight(mut self, weight: usize) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets a minimum width of the column.
+    pub fn min(mut self, width: usize) -> Self {
+        self.min = width;
+        self
+    }
+
+    /// Sets a maximum width of the column.
+    pub fn max(mut self, width: usize) -> Self {
+        self.max = Some(width);
+        self
+    }
+
+    /// Makes the column never shrink below its content width.
+    pub fn no_shrink(mut self) -> Self {
+        self.shrink = false;
+        self
+    }
+
+    /// Makes the column never grow above its content width.
+    pub fn no_grow(mut self) -> Self {
+        self.grow = false;
+        self
+    }
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Distribute sets a total width of a [`Table`],
+/// spreading the difference among columns in proportion to their [`Flex`] weights.
+///
+/// Columns which became narrower than their content are wrapped (or truncated, see [`Distribute::truncate`]).
+///
+/// Be aware that a table will not be made smaller than its borders and paddings,
+/// and that the constraints may not allow to reach the width exactly.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{width::Flex, Width, Style, Table};
+///
+/// let data = [
+///     ("1", "Hello World", "Some comment"),
+///     ("2", "Hello", "Another one"),
+/// ];
+///
+/// let table = Table::new(data)
+///     .with(Style::markdown())
+///     .with(
+///         Width::distribute(30)
+///             .column(0, Flex::fixed())
+///             .column(1, Flex::new().weight(2))
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "| &str | &str   | &str       |\n",
+///         "|------|--------|------------|\n",
+///         "| 1    | Hello  | Some comme |\n",
+///         "|      | World  | nt         |\n",
+///         "| 2    | Hello  | Another on |\n",
+///         "|      |        | e          |",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct Distribute<W = usize> {
+    width: W,
+    columns: Vec<Option<Flex>>,
+    keep_words: bool,
+    truncate: bool,
+}
+
+impl<W> Distribute<W>
+where
+    W: Measurement<Width>,
+{
+    /// Creates a [`Distribute`] object.
+    ///
+    /// All columns are [`Flex::new`] by default.
+    pub fn new(width: W) -> Self {
+        Self {
+            width,
+            columns: Vec::new(),
+            keep_words: false,
+            truncate: false,
+        }
+    }
+}
+
+impl<W> Distribute<W> {
+    /// Sets constraints of a given column.
+    pub fn column(mut self, column: usize, flex: Flex) -> Self {
+        if self.columns.len() <= column {
+            self.columns.resize(column + 1, None);
+        }
+
+        self.columns[column] = Some(flex);
+        self
+    }
+
+    /// Sets a [`Wrap::keep_words`] option to be used when a column content is wrapped.
+    ///
+    /// [`Wrap::keep_words`]: crate::width::Wrap::keep_words
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+
+    /// Truncates a content of shrunk columns instead of wrapping it.
+    pub fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
+    }
+
+    fn get_flex(&self, column: usize) -> Flex {
+        self.columns
+            .get(column)
+            .copied()
+            .flatten()
+            .unwrap_or_default()
+    }
+}
+
+impl<W, R> TableOption<R> for Distribute<W>
+where
+    W: Measurement<Width>,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.is_empty() {
+            return;
+        }
+
+        let width = self.width.measure(table.get_records(), table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        let cfg = table.get_config();
+        let (mut widths, total_width) = get_table_widths_with_total(table.get_records(), cfg);
+        let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_cols), cfg);
+        let content_widths = widths.clone();
+
+        let constraints = (0..count_cols)
+            .map(|col| {
+                let flex = self.get_flex(col);
+                let lower = std::cmp::max(flex.min, min_widths[col]);
+                let upper = flex.max.map(|max| std::cmp::max(max, lower));
+                (flex, lower, upper)
+            })
+            .collect::<Vec<_>>();
+
+        let mut total_width = total_width as isize;
+        for (col, &(_, lower, upper)) in constraints.iter().enumerate() {
+            let clamped = clamp_width(widths[col], lower, upper);
+            total_width += clamped as isize - widths[col] as isize;
+            widths[col] = clamped;
+        }
+
+        let diff = width as isize - total_width;
+        if diff > 0 {
+            let limits = constraints
+                .iter()
+                .map(|&(flex, _, upper)| match flex.grow {
+                    true => (flex.weight, upper.unwrap_or(usize::MAX)),
+                    false => (0, 0),
+                })
+                .collect::<Vec<_>>();
+
+            grow_widths(&mut widths, &limits, diff as usize);
+        } else if diff < 0 {
+            let limits = constraints
+                .iter()
+                .enumerate()
+                .map(|(col, &(flex, lower, _))| match flex.shrink {
+                    true => (flex.weight, lower),
+                    false => (0, std::cmp::max(lower, content_widths[col])),
+                })
+                .collect::<Vec<_>>();
+
+            shrink_widths(&mut widths, &limits, diff.unsigned_abs());
+        }
+
+        let cfg = table.get_config();
+        let points = get_decrease_cell_list(cfg, &widths, &min_widths, (count_rows, count_cols));
+        for ((row, col), width) in points {
+            if self.truncate {
+                Width::truncate(width).change_cell(table, (row, col).into());
+            } else {
+                let mut wrap = Width::wrap(width);
+                if self.keep_words {
+                    wrap = wrap.keep_words();
+                }
+
+                wrap.change_cell(table, (row, col).into());
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+        table.cache_width(widths);
+    }
+}
+
+fn clamp_width(width: usize, lower: usize, upper: Option<usize>) -> usize {
+    let width = std::cmp::max(width, lower);
+    match upper {
+        Some(upper) => std::cmp::min(width, upper),
+        None => width,
+    }
+}
+
+/// Increases widths by `diff` in proportion to weights,
+/// where `limits` is a list of `(weight, max width)`.
+fn grow_widths(widths: &mut [usize], limits: &[(usize, usize)], diff: usize) {
+    spread(
+        widths,
+        limits,
+        diff,
+        |width, max| width < max,
+        |width| *width += 1,
+    );
+}
+
+/// Decreases widths by `diff` in proportion to weights,
+/// where `limits` is a list of `(weight, min width)`.
+fn shrink_widths(widths: &mut [usize], limits: &[(usize, usize)], diff: usize) {
+    spread(
+        widths,
+        limits,
+        diff,
+        |width, min| width > min,
+        |width| *width -= 1,
+    );
+}
+
+/// A solver which hands out `diff` units one by one,
+/// each time to a column which is the most behind its weighted share.
+///
+/// It's similar to a [`Peaker`] but it takes weights into account.
+///
+/// [`Peaker`]: crate::peaker::Peaker
+fn spread<F, C>(
+    widths: &mut [usize],
+    limits: &[(usize, usize)],
+    diff: usize,
+    is_changeable: F,
+    mut change: C,
+) where
+    F: Fn(usize, usize) -> bool,
+    C: FnMut(&mut usize),
+{
+    let mut given = vec![0; widths.len()];
+    for _ in 0..diff {
+        // we compare given[i] / weight[i] ratios via a cross multiplication to stay in integers.
+        let col = (0..widths.len())
+            .filter(|&i| limits[i].0 > 0 && is_changeable(widths[i], limits[i].1))
+            .min_by(|&a, &b| {
+                let lhs = given[a] * limits[b].0;
+                let rhs = given[b] * limits[a].0;
+                lhs.cmp(&rhs).then_with(|| limits[b].0.cmp(&limits[a].0))
+            });
+
+        let col = match col {
+            Some(col) => col,
+            None => break,
+        };
+
+        change(&mut widths[col]);
+        given[col] += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grow_widths_by_weight_test() {
+        let mut widths = vec![1, 1, 1];
+        grow_widths(&mut widths, &[(1, 100), (2, 100), (1, 100)], 8);
+        assert_eq!(widths, [3, 5, 3]);
+    }
+
+    #[test]
+    fn grow_widths_respects_max_test() {
+        let mut widths = vec![1, 1, 1];
+        grow_widths(&mut widths, &[(1, 2), (2, 100), (0, 100)], 8);
+        assert_eq!(widths, [2, 8, 1]);
+    }
+
+    #[test]
+    fn shrink_widths_respects_min_test() {
+        let mut widths = vec![10, 10, 10];
+        shrink_widths(&mut widths, &[(1, 8), (1, 0), (0, 0)], 8);
+        assert_eq!(widths, [8, 4, 10]);
+    }
+}
@@ -0,0 +1,281 @@
+//! This module contains [`Fit`] structure, used to fit a [`Table`] into a given width
+//! by applying a chain of [`FitStep`]s.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{empty::EmptyRecords, Records, RecordsMut, Resizable},
+    Entity,
+};
+
+use crate::{
+    features::disable::TargetColumn,
+    locator::{ByColumnName, Locator},
+    measurement::Measurement,
+    object::Columns,
+    peaker::{Peaker, PriorityMax},
+    CellOption, Disable, Table, TableOption, Width,
+};
+
+use super::{
+    get_table_widths, get_table_widths_with_total,
+    truncate::{decrease_widths, get_decrease_cell_list},
+};
+
+/// A step which [`Fit`] may take to decrease a table width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitStep {
+    /// Removes left and right padding of all cells.
+    Padding,
+    /// Wraps the widest columns, but not below a given column width.
+    Wrap(usize),
+    /// Truncates the widest columns, but not below a given column width.
+    Truncate(usize),
+    /// Removes columns set by [`Fit::disable`], one by one, until the table fits.
+    Disable,
+}
+
+/// Fit tries to make a [`Table`] not wider than a given width,
+/// by applying a chain of [`FitStep`]s one after another until the table fits.
+///
+/// A column width limit used in [`FitStep::Wrap`] and [`FitStep::Truncate`]
+/// is the same as used by [`Width::list`], so it does include a padding.
+///
+/// The default chain is
+/// [`FitStep::Padding`], [`FitStep::Wrap`]`(10)`, [`FitStep::Truncate`]`(5)` and [`FitStep::Disable`].
+///
+/// You can check which of the steps were actually applied by [`Fit::applied`].
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{locator::ByColumnName, width::{Fit, FitStep}, Disable, Style, Table};
+///
+/// let data = [
+///     ("1", "Hello World", "A very long comment"),
+///     ("2", "Hello", "Another long comment"),
+/// ];
+///
+/// let mut fit = Fit::new(24)
+///     .steps([FitStep::Padding, FitStep::Wrap(12), FitStep::Disable])
+///     .disable([Disable::column(ByColumnName::new("comment"))]);
+///
+/// let table = Table::builder(data)
+///     .set_columns(["id", "name", "comment"])
+///     .clone()
+///     .build()
+///     .with(Style::markdown())
+///     .with(&mut fit)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "|id|name       |\n",
+///         "|--|-----------|\n",
+///         "|1 |Hello World|\n",
+///         "|2 |Hello      |",
+///     )
+/// );
+///
+/// assert_eq!(fit.applied(), [FitStep::Padding, FitStep::Wrap(12), FitStep::Disable]);
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct Fit<W = usize, L = ByColumnName<&'static str>> {
+    width: W,
+    steps: Vec<FitStep>,
+    disable: Vec<Disable<L, TargetColumn>>,
+    applied: Vec<FitStep>,
+}
+
+impl<W> Fit<W>
+where
+    W: Measurement<Width>,
+{
+    /// Creates a [`Fit`] object with a default chain of steps.
+    pub fn new(width: W) -> Self {
+        Self {
+            width,
+            steps: vec![
+                FitStep::Padding,
+                FitStep::Wrap(10),
+                FitStep::Truncate(5),
+                FitStep::Disable,
+            ],
+            disable: Vec::new(),
+            applied: Vec::new(),
+        }
+    }
+}
+
+impl<W, L> Fit<W, L> {
+    /// Sets a chain of steps which will be tried in the given order.
+    pub fn steps<I>(mut self, steps: I) -> Self
+    where
+        I: IntoIterator<Item = FitStep>,
+    {
+        self.steps = steps.into_iter().collect();
+        self
+    }
+
+    /// Sets a list of columns which can be removed on [`FitStep::Disable`].
+    ///
+    /// Columns are removed in the given order one by one until the table fits,
+    /// so the first one has the lowest priority.
+    pub fn disable<I, LL>(self, list: I) -> Fit<W, LL>
+    where
+        I: IntoIterator<Item = Disable<LL, TargetColumn>>,
+    {
+        Fit {
+            width: self.width,
+            steps: self.steps,
+            disable: list.into_iter().collect(),
+            applied: self.applied,
+        }
+    }
+
+    /// Returns a list of steps which were applied on the last use.
+    pub fn applied(&self) -> &[FitStep] {
+        &self.applied
+    }
+}
+
+impl<W, L, R> TableOption<R> for Fit<W, L>
+where
+    W: Measurement<Width>,
+    L: Locator<Coordinate = usize>,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.applied.clear();
+
+        // columns are located beforehand because other steps may change the content (e.g. a header).
+        let mut disable = self
+            .disable
+            .iter_mut()
+            .map(|d| {
+                d.get_locator_mut()
+                    .locate(table.get_records())
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let width = self.width.measure(table.get_records(), table.get_config());
+        for &step in &self.steps {
+            if table.is_empty() || table.total_width() <= width {
+                break;
+            }
+
+            let changed = match step {
+                FitStep::Padding => remove_horizontal_padding(table),
+                FitStep::Wrap(limit) => shrink_columns(table, width, limit, false),
+                FitStep::Truncate(limit) => shrink_columns(table, width, limit, true),
+                FitStep::Disable => remove_columns(table, width, &mut disable),
+            };
+
+            if changed {
+                self.applied.push(step);
+            }
+        }
+    }
+}
+
+fn remove_horizontal_padding<R>(table: &mut Table<R>) -> bool
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = table.shape();
+    let mut changed = false;
+    for row in 0..count_rows {
+        for col in 0..count_cols {
+            let entity = Entity::Cell(row, col);
+            let mut padding = *table.get_config().get_padding(entity);
+            if padding.left.size == 0 && padding.right.size == 0 {
+                continue;
+            }
+
+            padding.left.size = 0;
+            padding.right.size = 0;
+            table.get_config_mut().set_padding(entity, padding);
+            changed = true;
+        }
+    }
+
+    table.destroy_width_cache();
+
+    changed
+}
+
+fn shrink_columns<R>(table: &mut Table<R>, width: usize, limit: usize, truncate: bool) -> bool
+where
+    R: Records + RecordsMut<String>,
+{
+    let (count_rows, count_cols) = table.shape();
+    let cfg = table.get_config();
+    let (mut widths, total_width) = get_table_widths_with_total(table.get_records(), cfg);
+    let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_cols), cfg);
+    let limits = widths
+        .iter()
+        .zip(&min_widths)
+        .map(|(&width, &min)| std::cmp::max(min, std::cmp::min(width, limit)))
+        .collect::<Vec<_>>();
+
+    let original = widths.clone();
+    decrease_widths(
+        &mut widths,
+        &limits,
+        total_width,
+        width,
+        PriorityMax::create(),
+    );
+    if widths == original {
+        return false;
+    }
+
+    let points = get_decrease_cell_list(cfg, &widths, &min_widths, (count_rows, count_cols));
+    for ((row, col), width) in points {
+        if truncate {
+            Width::truncate(width).change_cell(table, (row, col).into());
+        } else {
+            Width::wrap(width).change_cell(table, (row, col).into());
+        }
+    }
+
+    table.destroy_width_cache();
+    table.destroy_height_cache();
+    table.cache_width(widths);
+
+    true
+}
+
+fn remove_columns<R>(table: &mut Table<R>, width: usize, list: &mut [Vec<usize>]) -> bool
+where
+    R: Records + Resizable,
+{
+    let mut removed: Vec<usize> = Vec::new();
+    for columns in list.iter_mut() {
+        if table.is_empty() || table.total_width() <= width {
+            break;
+        }
+
+        columns.retain(|col| !removed.contains(col));
+        columns.sort_unstable();
+        columns.dedup();
+
+        for &col in columns.iter().rev() {
+            let shift = removed.iter().filter(|&&c| c < col).count();
+            if col - shift >= table.count_columns() {
+                continue;
+            }
+
+            table.with(Disable::column(Columns::single(col - shift)));
+        }
+
+        removed.append(columns);
+    }
+
+    !removed.is_empty()
+}
@@ -3,6 +3,8 @@
 //! - [`Truncate`] cuts a cell content to limit width.
 //! - [`Wrap`] split the content via new lines in order to fit max width.
 //! - [`Justify`] sets columns width to the same value.
+//! - [`Fit`] fits a table into a width by a chain of steps, such as wrapping and removing columns.
+//! - [`Distribute`] spreads a table width among columns according to [`Flex`] constraints.
 //!
 //! To set a a table width, a combination of [`Width::truncate`] or [`Width::wrap`] and [`Width::increase`] can be used.
 //!
@@ -32,15 +34,19 @@
 //! );
 //! ```
 
+mod distribute;
+mod fit;
 mod justify;
 mod min_width;
 mod truncate;
 mod width_list;
 mod wrap;
 
-use crate::measurement::Measurement;
+use crate::{measurement::Measurement, Table};
 
 pub use self::{
+    distribute::{Distribute, Flex},
+    fit::{Fit, FitStep},
     justify::Justify,
     min_width::MinWidth,
     truncate::{SuffixLimit, Truncate},
@@ -48,7 +54,7 @@ pub use self::{
     wrap::Wrap,
 };
 
-use papergrid::{records::Records, width::WidthEstimator, Estimate, GridConfig};
+use papergrid::{records::Records, width::WidthEstimator, Estimate, GridConfig, Position};
 
 pub(crate) use wrap::wrap_text;
 
@@ -139,6 +145,27 @@ impl Width {
         Justify::new(width)
     }
 
+    /// Returns a [`Distribute`] structure.
+    ///
+    /// It sets a table width by spreading it among columns
+    /// in proportion to their [`Flex`] weights and limits.
+    pub fn distribute<W>(width: W) -> Distribute<W>
+    where
+        W: Measurement<Width>,
+    {
+        Distribute::new(width)
+    }
+
+    /// Returns a [`Fit`] structure.
+    ///
+    /// It tries to fit a table into a given width by a chain of [`FitStep`]s.
+    pub fn fit<W>(width: W) -> Fit<W>
+    where
+        W: Measurement<Width>,
+    {
+        Fit::new(width)
+    }
+
     /// Create [`WidthList`] to set a table width to a constant list of column widths.
     ///
     /// Notice if you provide a list with `.len()` smaller than `Table::count_columns` then it will have no affect.
@@ -201,6 +228,22 @@ pub(crate) fn count_borders(
         .count()
 }
 
+/// Returns a width of a cell content, considering its column span.
+pub(crate) fn get_cell_width<R>(table: &Table<R>, widths: &[usize], pos: Position) -> usize
+where
+    R: Records,
+{
+    let cfg = table.get_config();
+    let shape = table.shape();
+    let span = cfg.get_column_span(pos, shape).unwrap_or(1);
+    let end = std::cmp::min(pos.1 + span, shape.1);
+
+    let width = widths[pos.1..end].iter().sum::<usize>() + count_borders(cfg, pos.1, end, shape.1);
+
+    let padding = cfg.get_padding(pos.into());
+    width.saturating_sub(padding.left.size + padding.right.size)
+}
+
 pub(crate) fn get_table_total_width<W, R>(records: R, cfg: &GridConfig, ctrl: &W) -> usize
 where
     W: Estimate<R>,
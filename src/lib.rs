@@ -257,21 +257,27 @@ pub use papergrid;
 pub use crate::{
     features::{
         alignment::{self, Alignment},
+        chart, color_depth,
         concat::Concat,
+        conditional,
         disable::Disable,
         extract::Extract,
         format, formatting,
+        group_by::{self, GroupBy},
         height::{self, Height},
         highlight::Highlight,
+        join::Join,
         locator,
         margin::Margin,
         measurement, merge,
+        nested::Nested,
         padding::Padding,
         panel::{Footer, Header, Panel},
-        peaker,
-        rotate::Rotate,
+        peaker, rich,
+        rotate::{Rotate, VerticalText},
         shadow,
         span::Span,
+        split::Split,
         style::{self, Border, BorderText, Style},
         width::{self, Width},
     },
@@ -14,7 +14,11 @@ use papergrid::{
 };
 
 use crate::{
-    builder::Builder, height::get_table_total_height, object::Entity, width::get_table_total_width,
+    builder::{create_header_groups, set_header_groups, Builder},
+    color_depth::ColorDepth,
+    height::get_table_total_height,
+    object::Entity,
+    width::get_table_total_width,
     Tabled,
 };
 
@@ -82,8 +86,10 @@ pub struct Table<R = VecRecords<CellInfo<'static>>> {
     records: R,
     cfg: GridConfig,
     has_header: bool,
+    header_groups: usize,
     widths: Option<Vec<usize>>,
     heights: Option<Vec<usize>>,
+    color_depth: Option<ColorDepth>,
 }
 
 impl Table<VecRecords<CellInfo<'static>>> {
@@ -103,7 +109,9 @@ impl Table<VecRecords<CellInfo<'static>>> {
             CellMut::set(cell, text, &ctrl);
         }
 
-        let mut records = vec![header];
+        let (mut records, spans) = create_header_groups(T::header_groups(), T::LENGTH);
+        let count_groups = records.len();
+        records.push(header);
         for row in iter.into_iter() {
             let mut list = vec![CellInfo::default(); T::LENGTH];
             for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
@@ -115,7 +123,11 @@ impl Table<VecRecords<CellInfo<'static>>> {
 
         let mut b = Builder::custom(VecRecords::from(records));
         b.with_header();
-        b.build()
+
+        let mut table = b.build();
+        set_header_groups(&mut table, count_groups, spans);
+
+        table
     }
 }
 
@@ -186,6 +198,7 @@ impl Table<()> {
         let mut b = Builder::from(records);
         b.hint_column_size(T::LENGTH);
         b.set_columns(T::headers());
+        b.set_header_groups(T::header_groups());
 
         b
     }
@@ -227,10 +240,20 @@ impl<R> Table<R> {
     ///
     /// It's `true` when [`Table::new`] and [`Table::builder`] is used.
     /// In many other cases it's `false`.
+    ///
+    /// If a table has header groups the header goes right after them,
+    /// see [`Table::count_header_groups`].
     pub fn has_header(&self) -> bool {
         self.has_header
     }
 
+    /// Returns an amount of rows of header groups, which go first in a table.
+    ///
+    /// They are created by [`Table::new`] and [`Builder::set_header_groups`].
+    pub fn count_header_groups(&self) -> usize {
+        self.header_groups
+    }
+
     pub(crate) fn cache_width(&mut self, widths: Vec<usize>) {
         self.widths = Some(widths);
     }
@@ -239,6 +262,10 @@ impl<R> Table<R> {
         self.widths = None;
     }
 
+    pub(crate) fn get_width_cache(&self) -> Option<&[usize]> {
+        self.widths.as_deref()
+    }
+
     pub(crate) fn cache_height(&mut self, widths: Vec<usize>) {
         self.heights = Some(widths);
     }
@@ -247,9 +274,21 @@ impl<R> Table<R> {
         self.heights = None;
     }
 
+    pub(crate) fn get_height_cache(&self) -> Option<&[usize]> {
+        self.heights.as_deref()
+    }
+
     pub(crate) fn set_header_flag(&mut self, has_header: bool) {
         self.has_header = has_header;
     }
+
+    pub(crate) fn set_header_groups_count(&mut self, count: usize) {
+        self.header_groups = count;
+    }
+
+    pub(crate) fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = Some(depth);
+    }
 }
 
 impl<R> Table<R>
@@ -343,7 +382,12 @@ where
 
         let grid = Grid::new(&self.records, &cfg, &width, &height);
 
-        write!(f, "{grid}")
+        match self.color_depth {
+            Some(depth) if depth != ColorDepth::TrueColor => {
+                f.write_str(&depth.convert(&grid.to_string()))
+            }
+            _ => write!(f, "{grid}"),
+        }
     }
 }
 
@@ -356,8 +400,10 @@ where
             records,
             cfg: GridConfig::default(),
             has_header: false,
+            header_groups: 0,
             widths: None,
             heights: None,
+            color_depth: None,
         }
     }
 }
@@ -377,7 +423,9 @@ where
             CellMut::set(cell, text, &ctrl);
         }
 
-        let mut records = vec![header];
+        let (mut records, spans) = create_header_groups(T::header_groups(), T::LENGTH);
+        let count_groups = records.len();
+        records.push(header);
         for row in iter.into_iter() {
             let mut list = vec![CellInfo::default(); T::LENGTH];
             for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
@@ -389,7 +437,11 @@ where
 
         let mut b = Builder::custom(VecRecords::from(records));
         b.with_header();
-        b.build()
+
+        let mut table = b.build();
+        set_header_groups(&mut table, count_groups, spans);
+
+        table
     }
 }
 
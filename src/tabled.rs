@@ -18,6 +18,16 @@ pub trait Tabled {
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+    /// Header groups must return levels of groups which are placed above the headers,
+    /// starting from the top one.
+    ///
+    /// A group is a name and a number of columns it spans,
+    /// so spans of each level must sum up to [`Tabled::LENGTH`].
+    ///
+    /// By default there's no groups.
+    fn header_groups() -> Vec<Vec<(Cow<'static, str>, usize)>> {
+        Vec::new()
+    }
 }
 
 impl<T> Tabled for &T
@@ -32,6 +42,9 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn header_groups() -> Vec<Vec<(Cow<'static, str>, usize)>> {
+        T::header_groups()
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -46,6 +59,9 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn header_groups() -> Vec<Vec<(Cow<'static, str>, usize)>> {
+        T::header_groups()
+    }
 }
 
 macro_rules! tuple_table {
@@ -66,10 +82,42 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn header_groups() -> Vec<Vec<(Cow<'static, str>, usize)>> {
+                merge_header_groups(vec![$(($name::LENGTH, $name::header_groups()),)+])
+            }
         }
     };
 }
 
+type HeaderGroups = Vec<Vec<(Cow<'static, str>, usize)>>;
+
+/// Merges header groups of consecutive blocks of columns.
+///
+/// Levels are aligned by the bottom one,
+/// so blocks with less levels are padded by empty groups at the top.
+pub(crate) fn merge_header_groups(blocks: Vec<(usize, HeaderGroups)>) -> HeaderGroups {
+    let depth = blocks
+        .iter()
+        .map(|(_, levels)| levels.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut out = vec![Vec::new(); depth];
+    for (width, levels) in blocks {
+        let pad = depth - levels.len();
+        for level in &mut out[..pad] {
+            level.push((Cow::Borrowed(""), width));
+        }
+
+        for (level, groups) in out[pad..].iter_mut().zip(levels) {
+            level.extend(groups);
+        }
+    }
+
+    out
+}
+
 tuple_table! { A }
 tuple_table! { A B }
 tuple_table! { A B C }
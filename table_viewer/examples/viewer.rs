@@ -0,0 +1,29 @@
+//! The example shows a table of ASCII characters in a terminal pager.
+//!
+//! Use arrows to scroll, `/` to search, `s` to sort and `q` to quit.
+
+use table_viewer::{terminal, Viewer};
+use tabled::{builder::Builder, Style};
+
+fn main() -> std::io::Result<()> {
+    let mut builder = Builder::default();
+    builder.set_columns(["code", "char", "hex", "octal", "binary", "is alphabetic"]);
+
+    for code in 32u8..127 {
+        let c = code as char;
+        builder.add_record([
+            code.to_string(),
+            c.to_string(),
+            format!("{:#04x}", code),
+            format!("{:#05o}", code),
+            format!("{:#010b}", code),
+            c.is_ascii_alphabetic().to_string(),
+        ]);
+    }
+
+    let mut table = builder.build();
+    table.with(Style::modern());
+
+    let mut viewer = Viewer::new(table).frozen_columns(1);
+    terminal::run(&mut viewer)
+}
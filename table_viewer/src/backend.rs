@@ -0,0 +1,76 @@
+//! The module contains a [`Backend`] trait which is an abstraction over a terminal,
+//! and an in-memory [`TestBackend`].
+
+use std::io;
+
+/// Backend is a terminal on which a [`Viewer`] is drawn.
+///
+/// [`Viewer`]: crate::Viewer
+pub trait Backend {
+    /// Returns a size of a terminal as `(width, height)`.
+    fn size(&self) -> io::Result<(usize, usize)>;
+
+    /// Draws a frame, replacing everything drawn before.
+    ///
+    /// Each line is guaranteed to be no wider than a terminal.
+    fn draw(&mut self, lines: &[String]) -> io::Result<()>;
+}
+
+/// An in-memory [`Backend`] which keeps the last drawn frame.
+///
+/// ```
+/// use table_viewer::{backend::{Backend, TestBackend}, Viewer};
+/// use tabled::Table;
+///
+/// let mut backend = TestBackend::new(20, 5);
+///
+/// let mut viewer = Viewer::new(Table::new([1, 2, 3]));
+/// viewer.draw(&mut backend).unwrap();
+///
+/// assert_eq!(backend.lines()[0], "+-----+");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TestBackend {
+    width: usize,
+    height: usize,
+    lines: Vec<String>,
+}
+
+impl TestBackend {
+    /// Creates a backend of a given size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Changes a size of a backend.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Returns lines of the last drawn frame.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+impl Backend for TestBackend {
+    fn size(&self) -> io::Result<(usize, usize)> {
+        Ok((self.width, self.height))
+    }
+
+    fn draw(&mut self, lines: &[String]) -> io::Result<()> {
+        self.lines = lines.to_vec();
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for TestBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.lines.join("\n"))
+    }
+}
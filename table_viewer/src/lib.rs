@@ -0,0 +1,635 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+
+//! The library provides a [`Viewer`], a terminal pager for a [`Table`].
+//!
+//! It scrolls a table vertically and horizontally,
+//! keeping a header row and a given number of first columns frozen.
+//! It also supports a search (`/`) which highlights matched cells
+//! and sorting by a column (`s`).
+//!
+//! A viewer is drawn on a [`Backend`].
+//! [`TestBackend`] keeps frames in memory so a viewer can be tested,
+//! and with `crossterm` feature (on by default) [`run`] shows it in a real terminal.
+//!
+//! # Example
+//!
+//! ```
+//! use table_viewer::{backend::TestBackend, Key, Viewer};
+//! use tabled::Table;
+//!
+//! let data = [("Alice", 95), ("Bob", 70), ("Eve", 40), ("Mallory", 100)];
+//!
+//! let mut viewer = Viewer::new(Table::new(data));
+//! let mut backend = TestBackend::new(20, 7);
+//!
+//! viewer.handle(Key::Down);
+//! viewer.draw(&mut backend).unwrap();
+//!
+//! assert_eq!(
+//!     backend.to_string(),
+//!     concat!(
+//!         "+---------+-----+\n",
+//!         "| &str    | i32 |\n",
+//!         "+---------+-----+\n",
+//!         "| Bob     | 70  |\n",
+//!         "+---------+-----+\n",
+//!         "| Eve     | 40  |\n",
+//!         "rows 2-3 of 4",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: tabled::Table
+//! [`Backend`]: crate::backend::Backend
+//! [`TestBackend`]: crate::backend::TestBackend
+//! [`run`]: crate::terminal::run
+
+use std::{cmp::Ordering, io};
+
+use tabled::{
+    object::Cell,
+    papergrid::{
+        height::HeightEstimator,
+        records::{Records, Resizable},
+        width::WidthEstimator,
+        Estimate, Grid,
+    },
+    Border, Highlight, Table,
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::backend::Backend;
+
+pub mod backend;
+
+#[cfg(feature = "crossterm")]
+pub mod terminal;
+
+/// A key press handled by a [`Viewer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// Arrow up.
+    Up,
+    /// Arrow down.
+    Down,
+    /// Arrow left.
+    Left,
+    /// Arrow right.
+    Right,
+    /// Page up.
+    PageUp,
+    /// Page down.
+    PageDown,
+    /// Home.
+    Home,
+    /// End.
+    End,
+    /// Enter.
+    Enter,
+    /// Backspace.
+    Backspace,
+    /// Escape.
+    Esc,
+    /// A character.
+    Char(char),
+}
+
+/// Viewer is a pager which shows a part of a [`Table`] fitting into a terminal.
+///
+/// | Key                    | Action                                          |
+/// |------------------------|-------------------------------------------------|
+/// | `↑` `↓` / `k` `j`      | scroll by a row                                 |
+/// | `←` `→` / `h` `l`      | scroll by a column                              |
+/// | `PageUp` `PageDown`    | scroll by a page                                |
+/// | `Home` `End` / `g` `G` | go to the first or the last row                 |
+/// | `/`                    | start a search, `Enter` to confirm, `Esc` to cancel |
+/// | `n` `N`                | go to the next or the previous match            |
+/// | `s`                    | sort by the first scrollable visible column, again to reverse |
+/// | `q` `Esc`              | quit                                            |
+///
+/// A table is rendered by [`Grid`] on each frame,
+/// so a style and all options applied to it are kept.
+/// Colored tables are not supported, because lines are cut by characters.
+///
+/// [`Table`]: tabled::Table
+/// [`Grid`]: tabled::papergrid::Grid
+#[derive(Debug, Clone)]
+pub struct Viewer {
+    table: Table,
+    frozen_rows: usize,
+    frozen_columns: usize,
+    row: usize,
+    column: usize,
+    input: Option<String>,
+    query: Option<String>,
+    matches: Vec<(usize, usize)>,
+    current_match: usize,
+    sort: Option<(usize, bool)>,
+    viewport: (usize, usize),
+    /// Rows of a last drawn frame, used to scroll by a page.
+    rows: Option<Vec<usize>>,
+}
+
+impl Viewer {
+    /// Creates a viewer of a table.
+    ///
+    /// By default the first row is considered to be a header and is frozen.
+    pub fn new(table: Table) -> Self {
+        let row = std::cmp::min(1, table.count_rows());
+
+        Self {
+            table,
+            frozen_rows: 1,
+            frozen_columns: 0,
+            row,
+            column: 0,
+            input: None,
+            query: None,
+            matches: Vec::new(),
+            current_match: 0,
+            sort: None,
+            viewport: (80, 24),
+            rows: None,
+        }
+    }
+
+    /// Sets a number of first rows which are always shown.
+    ///
+    /// They are also never sorted.
+    pub fn frozen_rows(mut self, count: usize) -> Self {
+        self.frozen_rows = count;
+        self.row = std::cmp::min(count, self.table.count_rows());
+        self
+    }
+
+    /// Sets a number of first columns which are always shown.
+    pub fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self.column = 0;
+        self
+    }
+
+    /// Returns a table, which rows may be reordered by sorting.
+    pub fn get_table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Returns a first shown not frozen row and a first shown not frozen column.
+    pub fn offset(&self) -> (usize, usize) {
+        (self.row, self.frozen_columns + self.column)
+    }
+
+    /// Returns positions of cells matched by a search.
+    pub fn matches(&self) -> &[(usize, usize)] {
+        &self.matches
+    }
+
+    /// Handles a key press.
+    ///
+    /// It returns `false` if the viewer must be closed.
+    pub fn handle(&mut self, key: Key) -> bool {
+        if let Some(input) = &mut self.input {
+            match key {
+                Key::Char(c) => input.push(c),
+                Key::Backspace => {
+                    input.pop();
+                }
+                Key::Enter => {
+                    let query = self.input.take().unwrap_or_default();
+                    self.search(query);
+                }
+                Key::Esc => self.input = None,
+                _ => {}
+            }
+
+            return true;
+        }
+
+        match key {
+            Key::Char('q') | Key::Esc => return false,
+            Key::Up | Key::Char('k') => self.scroll_to(self.row.saturating_sub(1)),
+            Key::Down | Key::Char('j') => self.scroll_to(self.row + 1),
+            Key::PageUp => {
+                let page = self.page_size();
+                self.scroll_to(self.row.saturating_sub(page));
+            }
+            Key::PageDown => {
+                let page = self.page_size();
+                self.scroll_to(self.row + page);
+            }
+            Key::Home | Key::Char('g') => self.scroll_to(0),
+            Key::End | Key::Char('G') => self.scroll_to(usize::MAX),
+            Key::Left | Key::Char('h') => self.scroll_column_to(self.column.saturating_sub(1)),
+            Key::Right | Key::Char('l') => self.scroll_column_to(self.column + 1),
+            Key::Char('/') => self.input = Some(String::new()),
+            Key::Char('n') => self.next_match(false),
+            Key::Char('N') => self.next_match(true),
+            Key::Char('s') => {
+                let column = self.frozen_columns + self.column;
+                let desc = matches!(self.sort, Some((col, false)) if col == column);
+                self.sort_by(column, desc);
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    /// Searches cells which contain a given text and shows the first match
+    /// starting from a current row.
+    ///
+    /// An empty query resets a search.
+    pub fn search<S>(&mut self, query: S)
+    where
+        S: Into<String>,
+    {
+        let query = query.into();
+        self.query = if query.is_empty() { None } else { Some(query) };
+        self.find_matches();
+
+        self.current_match = self
+            .matches
+            .iter()
+            .position(|&(row, _)| row >= self.row)
+            .unwrap_or(0);
+
+        self.show_match();
+    }
+
+    /// Sorts not frozen rows by a column.
+    ///
+    /// Numbers are compared by value and go before any other text.
+    /// The sort is stable.
+    ///
+    /// Only the records are moved,
+    /// so settings of particular cells (like alignment, color, borders or spans)
+    /// stay at their positions.
+    pub fn sort_by(&mut self, column: usize, desc: bool) {
+        let (count_rows, count_columns) = self.table.shape();
+        if column >= count_columns {
+            return;
+        }
+
+        let start = std::cmp::min(self.frozen_rows, count_rows);
+
+        let records = self.table.get_records();
+        let mut order = (start..count_rows).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let ord = compare(records.get_text((a, column)), records.get_text((b, column)));
+            if desc {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+
+        let records = self.table.get_records_mut();
+        let mut places = (0..count_rows).collect::<Vec<_>>();
+        let mut rows = (0..count_rows).collect::<Vec<_>>();
+        for (i, row) in order.into_iter().enumerate() {
+            let target = start + i;
+            let place = places[row];
+            if place != target {
+                records.swap_row(target, place);
+
+                let moved = rows[target];
+                rows.swap(target, place);
+                places[row] = target;
+                places[moved] = place;
+            }
+        }
+
+        self.sort = Some((column, desc));
+        self.rows = None;
+        self.find_matches();
+    }
+
+    /// Draws a viewer on a backend.
+    pub fn draw<B>(&mut self, backend: &mut B) -> io::Result<()>
+    where
+        B: Backend,
+    {
+        let (width, height) = backend.size()?;
+        self.viewport = (width, height);
+
+        let frame = self.frame();
+        let lines = self.render_frame(&frame, width, height);
+        self.rows = Some(frame.rows);
+
+        backend.draw(&lines)
+    }
+
+    /// Renders lines of a frame of a given size.
+    ///
+    /// The last line is a status line.
+    pub fn render(&self, width: usize, height: usize) -> Vec<String> {
+        self.render_frame(&self.frame(), width, height)
+    }
+
+    fn render_frame(&self, frame: &Frame, width: usize, height: usize) -> Vec<String> {
+        if width == 0 || height == 0 {
+            return Vec::new();
+        }
+
+        let (count_rows, count_columns) = self.table.shape();
+        let frozen_rows = std::cmp::min(self.frozen_rows, count_rows);
+        let frozen_columns = std::cmp::min(self.frozen_columns, count_columns);
+
+        let view_height = height - 1;
+        let header_end = std::cmp::min(frame.rows[frozen_rows], view_height);
+        let body_start = frame.rows[std::cmp::max(self.row, frozen_rows)];
+        let body_end = std::cmp::min(frame.lines.len(), body_start + (view_height - header_end));
+
+        let frozen_width = std::cmp::min(frame.columns[frozen_columns], width);
+        let scroll_start =
+            frame.columns[std::cmp::min(frozen_columns + self.column, count_columns)];
+
+        let mut lines = frame.lines[..header_end]
+            .iter()
+            .chain(&frame.lines[body_start..body_end])
+            .map(|line| {
+                let mut buf = cut(line, 0, frozen_width);
+                buf.push_str(&cut(line, scroll_start, width - frozen_width));
+                buf
+            })
+            .collect::<Vec<_>>();
+
+        let row = std::cmp::max(self.row, frozen_rows);
+        let last_row = (row..count_rows)
+            .take_while(|&row| frame.rows[row] < body_end)
+            .last()
+            .or_else(|| Some(row).filter(|&row| row < count_rows));
+        lines.push(cut(&self.status(last_row), 0, width));
+
+        lines
+    }
+
+    fn frame(&self) -> Frame {
+        let mut table = self.table.clone();
+        for &(row, col) in &self.matches {
+            table.with(Highlight::new(Cell(row, col), Border::filled('*')));
+        }
+
+        let (count_rows, count_columns) = table.shape();
+        let records = table.get_records();
+        let cfg = table.get_config();
+
+        let mut widths = WidthEstimator::default();
+        widths.estimate(records, cfg);
+        let mut heights = HeightEstimator::default();
+        heights.estimate(records, cfg);
+
+        let text = Grid::new(records, cfg, &widths, &heights).to_string();
+        let lines = text.lines().map(String::from).collect::<Vec<_>>();
+
+        let widths = Vec::from(widths);
+        let heights = Vec::from(heights);
+
+        let margin = cfg.get_margin();
+
+        let mut rows = Vec::with_capacity(count_rows + 1);
+        let mut y = margin.top.size;
+        for (row, height) in heights.into_iter().enumerate() {
+            if cfg.has_horizontal(row, count_rows) {
+                y += 1;
+            }
+
+            rows.push(y);
+            y += height;
+        }
+        rows.push(lines.len());
+
+        let mut columns = Vec::with_capacity(count_columns + 1);
+        let mut x = margin.left.size;
+        for (col, width) in widths.into_iter().enumerate() {
+            if cfg.has_vertical(col, count_columns) {
+                x += 1;
+            }
+
+            columns.push(x);
+            x += width;
+        }
+        columns.push(lines.iter().map(|line| line.width()).max().unwrap_or(0));
+
+        Frame {
+            lines,
+            rows,
+            columns,
+        }
+    }
+
+    fn status(&self, last_row: Option<usize>) -> String {
+        if let Some(input) = &self.input {
+            return format!("/{}", input);
+        }
+
+        let count_rows = self.table.count_rows();
+        let frozen_rows = std::cmp::min(self.frozen_rows, count_rows);
+
+        let mut status = match last_row {
+            Some(last) => format!(
+                "rows {}-{} of {}",
+                self.row - frozen_rows + 1,
+                last - frozen_rows + 1,
+                count_rows - frozen_rows,
+            ),
+            None => String::from("no rows"),
+        };
+
+        if let Some((column, desc)) = self.sort {
+            let name = if frozen_rows > 0 {
+                self.table.get_records().get_text((0, column)).to_owned()
+            } else {
+                format!("column {}", column)
+            };
+
+            let order = if desc { "desc" } else { "asc" };
+            status.push_str(&format!(" | sorted by {} {}", name, order));
+        }
+
+        if let Some(query) = &self.query {
+            if self.matches.is_empty() {
+                status.push_str(&format!(" | /{} not found", query));
+            } else {
+                status.push_str(&format!(
+                    " | /{} {}/{}",
+                    query,
+                    self.current_match + 1,
+                    self.matches.len()
+                ));
+            }
+        }
+
+        status
+    }
+
+    fn page_size(&mut self) -> usize {
+        let count_rows = self.table.count_rows();
+        let frozen_rows = std::cmp::min(self.frozen_rows, count_rows);
+        if self.row >= count_rows {
+            return 1;
+        }
+
+        if self.rows.is_none() {
+            self.rows = Some(self.frame().rows);
+        }
+
+        let rows = self.rows.as_deref().unwrap_or_default();
+
+        let view_height = self.viewport.1.saturating_sub(1);
+        let body_height = view_height.saturating_sub(rows[frozen_rows]);
+        let start = rows[self.row];
+
+        let count = (self.row..count_rows)
+            .take_while(|&row| rows[row] - start < body_height)
+            .count();
+
+        std::cmp::max(count, 1)
+    }
+
+    fn scroll_to(&mut self, row: usize) {
+        // a body may be empty, then a row points right after the frozen ones.
+        let count_rows = self.table.count_rows();
+        let first = std::cmp::min(self.frozen_rows, count_rows);
+        let last = std::cmp::max(count_rows.saturating_sub(1), first);
+        self.row = row.clamp(first, last);
+    }
+
+    fn scroll_column_to(&mut self, column: usize) {
+        let count_columns = self.table.count_columns();
+        let last = count_columns.saturating_sub(self.frozen_columns + 1);
+        self.column = std::cmp::min(column, last);
+    }
+
+    fn find_matches(&mut self) {
+        self.matches.clear();
+        self.current_match = 0;
+        self.rows = None;
+
+        let query = match &self.query {
+            Some(query) => query,
+            None => return,
+        };
+
+        let (count_rows, count_columns) = self.table.shape();
+        let records = self.table.get_records();
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                if records.get_text((row, col)).contains(query.as_str()) {
+                    self.matches.push((row, col));
+                }
+            }
+        }
+    }
+
+    fn next_match(&mut self, back: bool) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        let count = self.matches.len();
+        self.current_match = if back {
+            (self.current_match + count - 1) % count
+        } else {
+            (self.current_match + 1) % count
+        };
+
+        self.show_match();
+    }
+
+    fn show_match(&mut self) {
+        let (row, col) = match self.matches.get(self.current_match) {
+            Some(&pos) => pos,
+            None => return,
+        };
+
+        if row >= self.frozen_rows {
+            self.scroll_to(row);
+        }
+
+        if col >= self.frozen_columns {
+            self.scroll_column_to(col - self.frozen_columns);
+        }
+    }
+}
+
+impl From<Table> for Viewer {
+    fn from(table: Table) -> Self {
+        Self::new(table)
+    }
+}
+
+/// A rendered table with positions of its rows and columns.
+struct Frame {
+    lines: Vec<String>,
+    /// A first line of each row, and a count of lines at the end.
+    rows: Vec<usize>,
+    /// A first character of each column, and a width of a table at the end.
+    columns: Vec<usize>,
+}
+
+fn compare(lhs: &str, rhs: &str) -> Ordering {
+    let number = |text: &str| text.trim().parse::<f64>().ok().filter(|n| !n.is_nan());
+    match (number(lhs), number(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => lhs.cmp(rhs),
+    }
+}
+
+/// Cuts a part of a line by display columns,
+/// partly visible wide characters are replaced by spaces.
+fn cut(line: &str, from: usize, width: usize) -> String {
+    let end = from + width;
+
+    let mut buf = String::new();
+    let mut x = 0;
+    for c in line.chars() {
+        if x >= end {
+            break;
+        }
+
+        let w = c.width().unwrap_or(0);
+        if x >= from && x + w <= end {
+            buf.push(c);
+        } else if x + w > from {
+            let visible = std::cmp::min(x + w, end) - std::cmp::max(x, from);
+            buf.push_str(&" ".repeat(visible));
+        }
+
+        x += w;
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cut_test() {
+        assert_eq!(cut("hello world", 0, 5), "hello");
+        assert_eq!(cut("hello world", 6, 100), "world");
+        assert_eq!(cut("hello", 10, 5), "");
+        assert_eq!(cut("一二三", 1, 4), " 二 ");
+        assert_eq!(cut("一二三", 2, 2), "二");
+    }
+
+    #[test]
+    fn compare_test() {
+        assert_eq!(compare("10", "9"), Ordering::Greater);
+        assert_eq!(compare("-1.5", "abc"), Ordering::Less);
+        assert_eq!(compare("abc", "abd"), Ordering::Less);
+    }
+}
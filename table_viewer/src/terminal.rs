@@ -0,0 +1,107 @@
+//! The module contains a [`CrosstermBackend`] and a [`run`] function
+//! which shows a [`Viewer`] in a real terminal.
+
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue, style,
+    terminal::{self, ClearType},
+};
+
+use crate::{backend::Backend, Key, Viewer};
+
+/// A [`Backend`] which draws on a terminal by [`crossterm`].
+#[derive(Debug)]
+pub struct CrosstermBackend<W> {
+    out: W,
+}
+
+impl<W> CrosstermBackend<W> {
+    /// Creates a backend which writes to a given output.
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+}
+
+impl<W> Backend for CrosstermBackend<W>
+where
+    W: Write,
+{
+    fn size(&self) -> io::Result<(usize, usize)> {
+        let (width, height) = terminal::size()?;
+        Ok((width as usize, height as usize))
+    }
+
+    fn draw(&mut self, lines: &[String]) -> io::Result<()> {
+        queue!(self.out, terminal::Clear(ClearType::All))?;
+        for (i, line) in lines.iter().enumerate() {
+            queue!(self.out, cursor::MoveTo(0, i as u16), style::Print(line))?;
+        }
+
+        self.out.flush()
+    }
+}
+
+/// Shows a [`Viewer`] on the standard output until it's closed.
+///
+/// It switches a terminal into a raw mode and an alternate screen,
+/// and restores it back on exit.
+pub fn run(viewer: &mut Viewer) -> io::Result<()> {
+    let mut backend = CrosstermBackend::new(io::stdout());
+
+    terminal::enable_raw_mode()?;
+    execute!(backend.out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = event_loop(viewer, &mut backend);
+
+    execute!(backend.out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    result
+}
+
+fn event_loop<W>(viewer: &mut Viewer, backend: &mut CrosstermBackend<W>) -> io::Result<()>
+where
+    W: Write,
+{
+    loop {
+        viewer.draw(backend)?;
+
+        let event = match event::read()? {
+            Event::Key(event) if event.kind != KeyEventKind::Release => event,
+            _ => continue,
+        };
+
+        if event.code == KeyCode::Char('c') && event.modifiers.contains(KeyModifiers::CONTROL) {
+            return Ok(());
+        }
+
+        if let Some(key) = convert_key(event) {
+            if !viewer.handle(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn convert_key(event: KeyEvent) -> Option<Key> {
+    let key = match event.code {
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Char(c) => Key::Char(c),
+        _ => return None,
+    };
+
+    Some(key)
+}
@@ -0,0 +1,305 @@
+use table_viewer::{backend::TestBackend, Key, Viewer};
+use tabled::{builder::Builder, Style, Table};
+
+fn viewer() -> Viewer {
+    let data = [
+        ("Alice", 95, "passed", "Paris"),
+        ("Bob", 70, "passed", "Berlin"),
+        ("Eve", 40, "failed", "Rome"),
+        ("Mallory", 100, "passed", "Madrid"),
+        ("Trent", 70, "passed", "Oslo"),
+    ];
+
+    let mut table = Table::new(data);
+    table.with(Style::modern());
+
+    Viewer::new(table)
+}
+
+fn draw(viewer: &mut Viewer, backend: &mut TestBackend, keys: &[Key]) -> String {
+    for &key in keys {
+        assert!(viewer.handle(key));
+    }
+
+    viewer.draw(backend).unwrap();
+    backend.to_string()
+}
+
+#[test]
+fn viewer_cuts_frame_test() {
+    let mut viewer = viewer();
+    let mut backend = TestBackend::new(30, 9);
+
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &[]),
+        concat!(
+            "┌─────────┬─────┬────────┬────\n",
+            "│ &str    │ i32 │ &str   │ &st\n",
+            "├─────────┼─────┼────────┼────\n",
+            "│ Alice   │ 95  │ passed │ Par\n",
+            "├─────────┼─────┼────────┼────\n",
+            "│ Bob     │ 70  │ passed │ Ber\n",
+            "├─────────┼─────┼────────┼────\n",
+            "│ Eve     │ 40  │ failed │ Rom\n",
+            "rows 1-3 of 5",
+        )
+    );
+}
+
+#[test]
+fn viewer_scroll_with_frozen_column_test() {
+    let mut viewer = viewer().frozen_columns(1);
+    let mut backend = TestBackend::new(30, 9);
+
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &[Key::Right, Key::Down]),
+        concat!(
+            "┌─────────┬────────┬────────┐\n",
+            "│ &str    │ &str   │ &str   │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Bob     │ passed │ Berlin │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Eve     │ failed │ Rome   │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Mallory │ passed │ Madrid │\n",
+            "rows 2-4 of 5",
+        )
+    );
+    assert_eq!(viewer.offset(), (2, 2));
+
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &[Key::PageDown]),
+        concat!(
+            "┌─────────┬────────┬────────┐\n",
+            "│ &str    │ &str   │ &str   │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Trent   │ passed │ Oslo   │\n",
+            "└─────────┴────────┴────────┘\n",
+            "rows 5-5 of 5",
+        )
+    );
+
+    draw(
+        &mut viewer,
+        &mut backend,
+        &[Key::Right, Key::Right, Key::Right, Key::End],
+    );
+    assert_eq!(viewer.offset(), (5, 3));
+
+    draw(
+        &mut viewer,
+        &mut backend,
+        &[Key::Left, Key::Left, Key::Left, Key::Home, Key::Up],
+    );
+    assert_eq!(viewer.offset(), (1, 1));
+}
+
+#[test]
+fn viewer_search_test() {
+    let mut viewer = viewer().frozen_columns(1);
+    let mut backend = TestBackend::new(40, 14);
+
+    let keys = [Key::Right, Key::Char('/'), Key::Char('p'), Key::Char('a')];
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &keys),
+        concat!(
+            "┌─────────┬────────┬────────┐\n",
+            "│ &str    │ &str   │ &str   │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Alice   │ passed │ Paris  │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Bob     │ passed │ Berlin │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Eve     │ failed │ Rome   │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Mallory │ passed │ Madrid │\n",
+            "├─────────┼────────┼────────┤\n",
+            "│ Trent   │ passed │ Oslo   │\n",
+            "└─────────┴────────┴────────┘\n",
+            "/pa",
+        )
+    );
+
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &[Key::Enter]),
+        concat!(
+            "┌─────────┬────────┬────────┐\n",
+            "│ &str    │ &str   │ &str   │\n",
+            "├─────────┼*********────────┤\n",
+            "│ Alice   │ passed * Paris  │\n",
+            "├─────────┼*********────────┤\n",
+            "│ Bob     │ passed * Berlin │\n",
+            "├─────────┼*********────────┤\n",
+            "│ Eve     │ failed │ Rome   │\n",
+            "├─────────┼*********────────┤\n",
+            "│ Mallory │ passed * Madrid │\n",
+            "├─────────┼*********────────┤\n",
+            "│ Trent   │ passed * Oslo   │\n",
+            "└─────────┴*********────────┘\n",
+            "rows 1-5 of 5 | /pa 1/4",
+        )
+    );
+    assert_eq!(viewer.matches(), [(1, 2), (2, 2), (4, 2), (5, 2)]);
+
+    draw(&mut viewer, &mut backend, &[Key::Char('n'), Key::Char('n')]);
+    assert_eq!(viewer.offset(), (4, 2));
+
+    draw(
+        &mut viewer,
+        &mut backend,
+        &[Key::Char('N'), Key::Char('N'), Key::Char('N')],
+    );
+    assert_eq!(viewer.offset(), (5, 2));
+
+    let keys = [Key::Char('/'), Key::Char('x'), Key::Enter];
+    let frame = draw(&mut viewer, &mut backend, &keys);
+    assert!(frame.ends_with("rows 5-5 of 5 | /x not found"));
+    assert!(viewer.matches().is_empty());
+
+    let keys = [Key::Char('/'), Key::Char('x'), Key::Backspace, Key::Esc];
+    let frame = draw(&mut viewer, &mut backend, &keys);
+    assert!(frame.ends_with("rows 5-5 of 5 | /x not found"));
+}
+
+#[test]
+fn viewer_sort_test() {
+    let mut viewer = viewer();
+    let mut backend = TestBackend::new(40, 14);
+
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &[Key::Right, Key::Char('s')]),
+        concat!(
+            "┌─────┬────────┬────────┐\n",
+            "│ i32 │ &str   │ &str   │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 40  │ failed │ Rome   │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 70  │ passed │ Berlin │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 70  │ passed │ Oslo   │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 95  │ passed │ Paris  │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 100 │ passed │ Madrid │\n",
+            "└─────┴────────┴────────┘\n",
+            "rows 1-5 of 5 | sorted by i32 asc",
+        )
+    );
+
+    assert_eq!(
+        draw(&mut viewer, &mut backend, &[Key::Char('s')]),
+        concat!(
+            "┌─────┬────────┬────────┐\n",
+            "│ i32 │ &str   │ &str   │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 100 │ passed │ Madrid │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 95  │ passed │ Paris  │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 70  │ passed │ Berlin │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 70  │ passed │ Oslo   │\n",
+            "├─────┼────────┼────────┤\n",
+            "│ 40  │ failed │ Rome   │\n",
+            "└─────┴────────┴────────┘\n",
+            "rows 1-5 of 5 | sorted by i32 desc",
+        )
+    );
+
+    viewer.sort_by(0, false);
+    assert_eq!(
+        viewer.get_table().to_string().lines().nth(3),
+        Some("│ Alice   │ 95  │ passed │ Paris  │")
+    );
+}
+
+#[test]
+fn viewer_without_body_test() {
+    let mut table = Table::new(Vec::<(i32, i32)>::new());
+    table.with(Style::ascii());
+
+    let mut viewer = Viewer::new(table);
+    let mut backend = TestBackend::new(40, 5);
+
+    assert_eq!(
+        draw(
+            &mut viewer,
+            &mut backend,
+            &[Key::Down, Key::PageDown, Key::Char('s')]
+        ),
+        concat!(
+            "+-----+-----+\n",
+            "| i32 | i32 |\n",
+            "+-----+-----+\n",
+            "no rows | sorted by i32 asc"
+        ),
+    );
+}
+
+#[test]
+fn viewer_empty_table_test() {
+    let mut viewer = Viewer::new(Builder::default().build());
+
+    assert_eq!(viewer.render(40, 10), vec![String::from("no rows")]);
+
+    let mut backend = TestBackend::new(40, 5);
+    assert_eq!(
+        draw(
+            &mut viewer,
+            &mut backend,
+            &[Key::Down, Key::PageDown, Key::End, Key::Char('s')]
+        ),
+        "no rows",
+    );
+}
+
+#[test]
+fn viewer_frozen_rows_more_than_rows_test() {
+    let mut table = Table::new([1, 2]);
+    table.with(Style::ascii());
+
+    let mut viewer = Viewer::new(table).frozen_rows(5);
+    assert_eq!(viewer.offset(), (3, 0));
+
+    let mut backend = TestBackend::new(40, 10);
+    assert_eq!(
+        draw(
+            &mut viewer,
+            &mut backend,
+            &[Key::Down, Key::PageDown, Key::Up]
+        ),
+        concat!(
+            "+-----+\n",
+            "| i32 |\n",
+            "+-----+\n",
+            "| 1   |\n",
+            "+-----+\n",
+            "| 2   |\n",
+            "+-----+\n",
+            "no rows",
+        ),
+    );
+    assert_eq!(viewer.offset(), (3, 0));
+}
+
+#[test]
+fn viewer_page_down_after_sort_test() {
+    let mut viewer = viewer();
+    let mut backend = TestBackend::new(40, 8);
+
+    draw(&mut viewer, &mut backend, &[]);
+    draw(&mut viewer, &mut backend, &[Key::Char('s'), Key::PageDown]);
+
+    assert_eq!(viewer.offset(), (3, 0));
+}
+
+#[test]
+fn viewer_quit_test() {
+    let mut viewer = viewer();
+
+    assert!(viewer.handle(Key::Char('/')));
+    assert!(viewer.handle(Key::Char('q')));
+    assert!(viewer.handle(Key::Esc));
+    assert!(!viewer.handle(Key::Char('q')));
+    assert!(!viewer.handle(Key::Esc));
+}
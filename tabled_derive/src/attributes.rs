@@ -12,6 +12,7 @@ pub struct Attributes {
     pub display_with: Option<String>,
     pub display_with_use_self: bool,
     pub order: Option<usize>,
+    pub group: Option<String>,
 }
 
 impl Attributes {
@@ -58,6 +59,7 @@ impl Attributes {
                 self.display_with_use_self = use_self;
             }
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            parse::TabledAttrKind::Group(value) => self.group = Some(value.value()),
         }
 
         Ok(())
@@ -36,6 +36,13 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let info = collect_info(ast, &attrs).map_err(error::abort).unwrap();
     let fields = info.values;
     let headers = info.headers;
+    let groups = info.groups.map(|groups| {
+        quote! {
+            fn header_groups() -> Vec<Vec<(::std::borrow::Cow<'static, str>, usize)>> {
+                #groups
+            }
+        }
+    });
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -50,6 +57,8 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #groups
         }
     };
 
@@ -165,6 +174,8 @@ fn info_from_fields(
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut groups = Vec::new();
+    let mut has_groups = false;
     let mut reorder = HashMap::new();
 
     let mut skipped = 0;
@@ -188,6 +199,12 @@ fn info_from_fields(
         let header = field_headers(field, i, &attributes, header_prefix);
         headers.push(header);
 
+        // an inlined type may have groups of its own.
+        has_groups |= attributes.group.is_some() || attributes.inline;
+
+        let group = field_header_groups(field, &attributes);
+        groups.push(group);
+
         let field_name = field_name(i, field);
         let value = get_field_fields(&field_name, &attributes);
         values.push(value);
@@ -196,6 +213,7 @@ fn info_from_fields(
     if !reorder.is_empty() {
         values = reorder_fields(&reorder, &values);
         headers = reorder_fields(&reorder, &headers);
+        groups = reorder_fields(&reorder, &groups);
     }
 
     let headers = quote!({
@@ -210,7 +228,13 @@ fn info_from_fields(
         out
     });
 
-    Ok(Impl { headers, values })
+    let groups = has_groups.then(|| merge_header_groups(&groups));
+
+    Ok(Impl {
+        headers,
+        values,
+        groups,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -264,8 +288,70 @@ fn field_headers(
     }
 }
 
+fn field_header_groups(field: &Field, attributes: &Attributes) -> TokenStream {
+    let (width, levels) = if attributes.inline {
+        let field_type = &field.ty;
+        (
+            quote!(<#field_type as Tabled>::LENGTH),
+            quote!(<#field_type as Tabled>::header_groups()),
+        )
+    } else {
+        (quote!(1), quote!(Vec::new()))
+    };
+
+    header_groups_block(width, levels, attributes.group.as_deref())
+}
+
+/// Creates a block of columns with their header groups,
+/// which is put under a group with a given name if there's one.
+fn header_groups_block(
+    width: TokenStream,
+    levels: TokenStream,
+    group: Option<&str>,
+) -> TokenStream {
+    match group {
+        Some(name) => quote!({
+            let width = #width;
+            let mut levels: Vec<Vec<(::std::borrow::Cow<'static, str>, usize)>> = #levels;
+            levels.insert(0, vec![(::std::borrow::Cow::Borrowed(#name), width)]);
+            (width, levels)
+        }),
+        None => quote!((#width, #levels)),
+    }
+}
+
+/// Merges header groups of blocks, aligning their levels by the bottom one,
+/// so blocks with less levels are padded by empty groups at the top.
+fn merge_header_groups(blocks: &[TokenStream]) -> TokenStream {
+    quote!({
+        let blocks: Vec<(usize, Vec<Vec<(::std::borrow::Cow<'static, str>, usize)>>)> =
+            vec![#(#blocks,)*];
+        let depth = blocks
+            .iter()
+            .map(|(_, levels)| levels.len())
+            .max()
+            .unwrap_or(0);
+
+        let mut out = vec![Vec::new(); depth];
+        for (width, levels) in blocks {
+            let pad = depth - levels.len();
+            for level in &mut out[..pad] {
+                level.push((::std::borrow::Cow::Borrowed(""), width));
+            }
+
+            for (level, groups) in out[pad..].iter_mut().zip(levels) {
+                level.extend(groups);
+            }
+        }
+
+        out
+    })
+}
+
 fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, Error> {
     let mut headers_list = Vec::new();
+    let mut groups_list = Vec::new();
+    let mut has_groups = false;
     let mut variants = Vec::new();
     for variant in &ast.variants {
         let mut attributes = Attributes::parse(&variant.attrs)?;
@@ -277,6 +363,17 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         let info = info_from_variant(variant, &attributes, attrs)?;
         variants.push((variant, info.values));
         headers_list.push(info.headers);
+
+        let width = if attributes.inline {
+            get_fields_length(&variant.fields)?
+        } else {
+            quote!(1)
+        };
+        has_groups |= attributes.group.is_some() || info.groups.is_some();
+
+        let levels = info.groups.unwrap_or_else(|| quote!(Vec::new()));
+        let group = header_groups_block(width, levels, attributes.group.as_deref());
+        groups_list.push(group);
     }
 
     let variant_sizes = get_enum_variant_length(ast)
@@ -291,7 +388,13 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    let groups = has_groups.then(|| merge_header_groups(&groups_list));
+
+    Ok(Impl {
+        headers,
+        values,
+        groups,
+    })
 }
 
 fn info_from_variant(
@@ -315,12 +418,18 @@ fn info_from_variant(
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![::std::borrow::Cow::Borrowed(#value)] };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        groups: None,
+    })
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    // it's set only if there's any group, otherwise a default method is used.
+    groups: Option<TokenStream>,
 }
 
 fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> TokenStream {
@@ -33,6 +33,7 @@ pub enum TabledAttrKind {
     RenameAll(LitStr),
     DisplayWith(LitStr, bool),
     Order(LitInt),
+    Group(LitStr),
 }
 
 impl Parse for TabledAttr {
@@ -52,6 +53,7 @@ impl Parse for TabledAttr {
                     "rename" => return Ok(Self::new(name, Rename(lit))),
                     "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
                     "display_with" => return Ok(Self::new(name, DisplayWith(lit, false))),
+                    "group" => return Ok(Self::new(name, Group(lit))),
                     _ => {}
                 }
             }
@@ -0,0 +1,123 @@
+use tabled::{display::AutoDisplay, object::Segment, Alignment, Modify, Style, Width};
+
+use crate::util::{create_vector, static_table};
+
+mod util;
+
+#[test]
+fn auto_display_regular() {
+    let data = create_vector::<2, 2>();
+
+    let display = AutoDisplay::new(&data, 30);
+
+    assert!(!display.is_expanded());
+    assert_eq!(
+        display.to_string(),
+        static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 | 0-0      | 0-1      |"
+            "+---+----------+----------+"
+            "| 1 | 1-0      | 1-1      |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn auto_display_expanded() {
+    let data = create_vector::<2, 2>();
+
+    let display = AutoDisplay::new(&data, 20);
+
+    assert!(display.is_expanded());
+    assert_eq!(
+        display.to_string(),
+        static_table!(
+            "-[ RECORD 0 ]-"
+            "N        | 0"
+            "column 0 | 0-0"
+            "column 1 | 0-1"
+            "-[ RECORD 1 ]-"
+            "N        | 1"
+            "column 0 | 1-0"
+            "column 1 | 1-1"
+        )
+    );
+}
+
+#[test]
+fn auto_display_shared_options() {
+    let data = create_vector::<1, 2>();
+
+    let mut display = AutoDisplay::new(&data, 10);
+    display
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Alignment::right()));
+
+    assert_eq!(
+        display.to_string(),
+        static_table!(
+            "-[ RECORD 0 ]-─┐"
+            "│       N │   0│"
+            "├─────────┼────┤"
+            "│column 0 │ 0-0│"
+            "├─────────┼────┤"
+            "│column 1 │ 0-1│"
+            "└─────────┴────┘"
+        )
+    );
+}
+
+#[test]
+fn auto_display_width_is_checked_after_options() {
+    let data = create_vector::<1, 2>();
+
+    let mut display = AutoDisplay::new(&data, 20);
+    assert!(display.is_expanded());
+
+    display.with_table(Width::truncate(20));
+    assert!(!display.is_expanded());
+
+    display.get_expanded_mut().header(|i| format!("#{i}"));
+    display.with_table(Width::increase(30));
+
+    assert_eq!(
+        display.to_string(),
+        static_table!(
+            "#0-------+----"
+            "N        | 0"
+            "column 0 | 0-0"
+            "column 1 | 0-1"
+        )
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn auto_display_expanded_header_groups() {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct Item {
+        #[tabled(group = "id")]
+        key: u8,
+        name: &'static str,
+    }
+
+    let display = AutoDisplay::new([Item { key: 1, name: "a" }, Item { key: 2, name: "b" }], 5);
+
+    assert!(display.is_expanded());
+    assert_eq!(
+        display.to_string(),
+        static_table!(
+            "-[ RECORD 0 ]-"
+            "key  | 1"
+            "name | a"
+            "-[ RECORD 1 ]-"
+            "key  | 2"
+            "name | b"
+        )
+    );
+}
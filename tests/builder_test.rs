@@ -1,6 +1,9 @@
 use std::iter::FromIterator;
 
-use tabled::builder::Builder;
+use tabled::{
+    builder::{Builder, TreeBuilder, TreeGuides},
+    Style,
+};
 
 use util::test_table;
 
@@ -738,6 +741,156 @@ test_table!(
     "+---+------+---------+-----------+"
 );
 
+fn tree() -> TreeBuilder<'static> {
+    let mut builder = Builder::default();
+    builder.set_columns(["name", "size", "modified"]);
+    builder.add_record(["/", "", ""]);
+
+    let mut tree = builder.tree(0);
+    let src = tree.add_child(0, ["src", "", "2022-10-01"]);
+    let features = tree.add_child(src, ["features", "", "2022-10-03"]);
+    tree.add_child(features, ["span.rs", "4K", "2022-10-03"]);
+    tree.add_child(features, ["merge.rs", "9K", "2022-10-02"]);
+    tree.add_child(src, ["lib.rs", "12K", "2022-09-30"]);
+    tree.add_child(0, ["Cargo.toml", "1K", "2022-09-01"]);
+    tree
+}
+
+test_table!(
+    tree_builder,
+    tree().build().with(Style::psql()),
+    " name              | size | modified   "
+    "-------------------+------+------------"
+    " /                 |      |            "
+    " ├─ src            |      | 2022-10-01 "
+    " │  ├─ features    |      | 2022-10-03 "
+    " │  │  ├─ span.rs  | 4K   | 2022-10-03 "
+    " │  │  └─ merge.rs | 9K   | 2022-10-02 "
+    " │  └─ lib.rs      | 12K  | 2022-09-30 "
+    " └─ Cargo.toml     | 1K   | 2022-09-01 "
+);
+
+test_table!(
+    tree_builder_collapse,
+    tree().collapse(1).clone().build().with(Style::psql()),
+    " name          | size | modified   "
+    "---------------+------+------------"
+    " /             |      |            "
+    " ├─ src        |      | 2022-10-01 "
+    " └─ Cargo.toml | 1K   | 2022-09-01 "
+);
+
+test_table!(
+    tree_builder_collapse_roots,
+    tree().collapse(0).clone().build().with(Style::psql()),
+    " name | size | modified "
+    "------+------+----------"
+    " /    |      |          "
+);
+
+test_table!(
+    tree_builder_ascii_guides,
+    tree()
+        .set_guides(TreeGuides::ascii())
+        .clone()
+        .build()
+        .with(Style::ascii()),
+    "+-------------------+------+------------+"
+    "| name              | size | modified   |"
+    "+-------------------+------+------------+"
+    "| /                 |      |            |"
+    "+-------------------+------+------------+"
+    "| |- src            |      | 2022-10-01 |"
+    "+-------------------+------+------------+"
+    "| |  |- features    |      | 2022-10-03 |"
+    "+-------------------+------+------------+"
+    "| |  |  |- span.rs  | 4K   | 2022-10-03 |"
+    "+-------------------+------+------------+"
+    "| |  |  `- merge.rs | 9K   | 2022-10-02 |"
+    "+-------------------+------+------------+"
+    "| |  `- lib.rs      | 12K  | 2022-09-30 |"
+    "+-------------------+------+------------+"
+    "| `- Cargo.toml     | 1K   | 2022-09-01 |"
+    "+-------------------+------+------------+"
+);
+
+test_table!(
+    tree_builder_custom_guides_in_other_column,
+    {
+        let mut tree = Builder::default().tree(1);
+        let root = tree.add_root(["1", "root"]);
+        let child = tree.add_child(root, ["2", "child"]);
+        tree.add_child(child, ["3", "leaf"]);
+        tree.add_child(root, ["4", "child"]);
+        tree.set_guides(TreeGuides::new("+ ", "+ ", ": ", "  "));
+        tree.build().with(Style::markdown())
+    },
+    "| 1 | root     |"
+    "|---|----------|"
+    "| 2 | + child  |"
+    "| 3 | : + leaf |"
+    "| 4 | + child  |"
+);
+
+test_table!(
+    tree_builder_multiline,
+    {
+        let mut tree = Builder::default().tree(0);
+        let root = tree.add_root(["root"]);
+        tree.add_child(root, ["first\nline"]);
+        tree.add_child(root, ["second\nline"]);
+        tree.build().with(Style::modern().off_horizontal())
+    },
+    "┌───────────┐"
+    "│ root      │"
+    "│ ├─ first  │"
+    "│ │  line   │"
+    "│ └─ second │"
+    "│    line   │"
+    "└───────────┘"
+);
+
+test_table!(
+    tree_builder_unknown_parent_and_short_rows,
+    {
+        let mut builder = Builder::default();
+        builder.set_columns(["a", "b", "c"]);
+
+        let mut tree = builder.tree(2);
+        let root = tree.add_root(["1"]);
+        tree.add_child(root, ["2", "", "child"]);
+        tree.add_child(100, ["3", "", "orphan"]);
+        tree.build()
+    },
+    "+---+---+----------+"
+    "| a | b | c        |"
+    "+---+---+----------+"
+    "| 1 |   |          |"
+    "+---+---+----------+"
+    "| 2 |   | └─ child |"
+    "+---+---+----------+"
+    "| 3 |   | orphan   |"
+    "+---+---+----------+"
+);
+
+test_table!(
+    tree_builder_column_out_of_range,
+    {
+        let mut tree = Builder::default().tree(5);
+        let root = tree.add_root(["root"]);
+        tree.add_root(["other"]);
+        tree.add_child(root, ["child"]);
+        tree.build()
+    },
+    "+-------+"
+    "| root  |"
+    "+-------+"
+    "| child |"
+    "+-------+"
+    "| other |"
+    "+-------+"
+);
+
 #[quickcheck_macros::quickcheck]
 #[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
 fn qc_table_is_consistent(data: Vec<Vec<isize>>) -> bool {
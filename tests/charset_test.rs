@@ -0,0 +1,118 @@
+use tabled::{
+    object::Cell,
+    style::{Border, BorderText, Charset, HorizontalLine, RawStyle, VerticalLine},
+    Modify, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table() -> Table {
+    Table::new([("Hello", 1), ("World", 2)])
+}
+
+test_table!(
+    charset_ascii_modern,
+    table().with(Style::modern()).with(Charset::Ascii),
+    "+-------+-----+"
+    "| &str  | i32 |"
+    "+-------+-----+"
+    "| Hello | 1   |"
+    "+-------+-----+"
+    "| World | 2   |"
+    "+-------+-----+"
+);
+
+test_table!(
+    charset_ascii_extended,
+    table().with(Style::extended()).with(Charset::Ascii),
+    "+=======+=====+"
+    "| &str  | i32 |"
+    "+=======+=====+"
+    "| Hello | 1   |"
+    "+=======+=====+"
+    "| World | 2   |"
+    "+=======+=====+"
+);
+
+test_table!(
+    charset_unicode_is_noop,
+    table().with(Style::rounded()).with(Charset::Unicode),
+    "╭───────┬─────╮"
+    "│ &str  │ i32 │"
+    "├───────┼─────┤"
+    "│ Hello │ 1   │"
+    "│ World │ 2   │"
+    "╰───────┴─────╯"
+);
+
+test_table!(
+    charset_ascii_keeps_lines,
+    table()
+        .with(
+            Style::modern()
+                .off_horizontal()
+                .horizontals([HorizontalLine::new(1, Style::extended().get_horizontal())])
+                .verticals([VerticalLine::new(1, Style::extended().get_vertical())])
+        )
+        .with(Charset::Ascii),
+    "+-------+-----+"
+    "| &str  | i32 |"
+    "+=======+=====+"
+    "| Hello | 1   |"
+    "| World | 2   |"
+    "+-------+-----+"
+);
+
+test_table!(
+    charset_ascii_keeps_border_text_and_cell_borders,
+    table()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 1)).with(Border::filled('█').top('═')))
+        .with(BorderText::first("┤ Title ├"))
+        .with(Charset::Ascii),
+    "+ Title +-----+"
+    "| &str  | i32 |"
+    "+-------█=====█"
+    "| Hello █ 1   █"
+    "+-------███████"
+    "| World | 2   |"
+    "+-------+-----+"
+);
+
+test_table!(
+    raw_style_to_ascii,
+    table().with(RawStyle::from(Style::rounded()).to_ascii()),
+    "+-------+-----+"
+    "| &str  | i32 |"
+    "+-------+-----+"
+    "| Hello | 1   |"
+    "| World | 2   |"
+    "+-------+-----+"
+);
+
+#[test]
+fn charset_detect() {
+    let detect = |vars: &[(&str, &str)]| {
+        Charset::detect(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    };
+
+    assert_eq!(detect(&[("LANG", "en_US.UTF-8")]), Charset::Unicode);
+    assert_eq!(detect(&[("LANG", "en_US.utf8")]), Charset::Unicode);
+    assert_eq!(detect(&[("LANG", "C")]), Charset::Ascii);
+    assert_eq!(detect(&[("LANG", "en_US.ISO-8859-1")]), Charset::Ascii);
+    assert_eq!(
+        detect(&[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")]),
+        Charset::Ascii
+    );
+    assert_eq!(
+        detect(&[("LC_ALL", ""), ("LC_CTYPE", "C.UTF-8"), ("LANG", "C")]),
+        Charset::Unicode
+    );
+    assert_eq!(detect(&[("WT_SESSION", "1")]), Charset::Unicode);
+}
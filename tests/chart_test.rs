@@ -0,0 +1,194 @@
+use tabled::{
+    chart::{Bar, Sparkline},
+    object::{Cell, Columns, Object, Rows},
+    Modify, Span, Table, Width,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table() -> Table {
+    let data = [
+        ("cpu", "100", "1 5 3"),
+        ("mem", "42", "2,4,8"),
+        ("disk", "7", "-"),
+    ];
+
+    let mut builder = Table::builder(data);
+    builder.set_columns(["name", "usage", "history"]);
+    builder.build()
+}
+
+test_table!(
+    bar_fills_column,
+    table().with(Modify::new(Columns::single(1).not(Rows::first())).with(Bar::new(100.0))),
+    "+------+-------+---------+"
+    "| name | usage | history |"
+    "+------+-------+---------+"
+    "| cpu  | █████ | 1 5 3   |"
+    "+------+-------+---------+"
+    "| mem  | ██▏   | 2,4,8   |"
+    "+------+-------+---------+"
+    "| disk | ▍     | -       |"
+    "+------+-------+---------+"
+);
+
+test_table!(
+    bar_fills_column_after_justify,
+    table()
+        .with(Width::justify(10))
+        .with(Modify::new(Columns::single(1).not(Rows::first())).with(Bar::new(100.0))),
+    "+------------+------------+------------+"
+    "| name       | usage      | history    |"
+    "+------------+------------+------------+"
+    "| cpu        | ██████████ | 1 5 3      |"
+    "+------------+------------+------------+"
+    "| mem        | ████▎      | 2,4,8      |"
+    "+------------+------------+------------+"
+    "| disk       | ▊          | -          |"
+    "+------------+------------+------------+"
+);
+
+test_table!(
+    bar_fills_column_after_increase,
+    table()
+        .with(Width::increase(40))
+        .with(Modify::new(Columns::single(1).not(Rows::first())).with(Bar::new(50.0))),
+    "+-----------+------------+-------------+"
+    "| name      | usage      | history     |"
+    "+-----------+------------+-------------+"
+    "| cpu       | ██████████ | 1 5 3       |"
+    "+-----------+------------+-------------+"
+    "| mem       | ████████▍  | 2,4,8       |"
+    "+-----------+------------+-------------+"
+    "| disk      | █▍         | -           |"
+    "+-----------+------------+-------------+"
+);
+
+test_table!(
+    bar_width,
+    table().with(Modify::new(Columns::single(1)).with(Bar::new(100.0).width(3))),
+    "+------+-------+---------+"
+    "| name | usage | history |"
+    "+------+-------+---------+"
+    "| cpu  | ███   | 1 5 3   |"
+    "+------+-------+---------+"
+    "| mem  | █▎    | 2,4,8   |"
+    "+------+-------+---------+"
+    "| disk | ▎     | -       |"
+    "+------+-------+---------+"
+);
+
+test_table!(
+    bar_label,
+    table().with(Modify::new(Columns::single(1)).with(Bar::new(200.0).width(12).label(true))),
+    "+------+--------------+---------+"
+    "| name | usage        | history |"
+    "+------+--------------+---------+"
+    "| cpu  | ████     100 | 1 5 3   |"
+    "+------+--------------+---------+"
+    "| mem  | █▉        42 | 2,4,8   |"
+    "+------+--------------+---------+"
+    "| disk | ▍          7 | -       |"
+    "+------+--------------+---------+"
+);
+
+test_table!(
+    bar_percent,
+    Table::new(["0%", "12.5%", "50", "150%", "-5%"])
+        .with(Modify::new(Rows::new(1..)).with(Bar::percent().width(10))),
+    "+------------+"
+    "| &str       |"
+    "+------------+"
+    "|         0% |"
+    "+------------+"
+    "| ▌    12.5% |"
+    "+------------+"
+    "| ███    50% |"
+    "+------------+"
+    "| █████ 150% |"
+    "+------------+"
+    "|        -5% |"
+    "+------------+"
+);
+
+test_table!(
+    bar_spanned_cell,
+    table()
+        .with(Modify::new(Cell(1, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 1)).with(Bar::new(100.0))),
+    "+------+-------+---------+"
+    "| name | usage | history |"
+    "+------+-------+---------+"
+    "| cpu  | ███████████████ |"
+    "+------+-------+---------+"
+    "| mem  | 42    | 2,4,8   |"
+    "+------+-------+---------+"
+    "| disk | 7     | -       |"
+    "+------+-------+---------+"
+);
+
+test_table!(
+    sparkline,
+    table().with(Modify::new(Columns::single(2)).with(Sparkline::new())),
+    "+------+-------+---------+"
+    "| name | usage | history |"
+    "+------+-------+---------+"
+    "| cpu  | 100   | ▁█▅     |"
+    "+------+-------+---------+"
+    "| mem  | 42    | ▁▃█     |"
+    "+------+-------+---------+"
+    "| disk | 7     | -       |"
+    "+------+-------+---------+"
+);
+
+test_table!(
+    sparkline_range,
+    table().with(Modify::new(Columns::single(2)).with(Sparkline::new().range(0.0, 10.0))),
+    "+------+-------+---------+"
+    "| name | usage | history |"
+    "+------+-------+---------+"
+    "| cpu  | 100   | ▂▅▃     |"
+    "+------+-------+---------+"
+    "| mem  | 42    | ▂▄▇     |"
+    "+------+-------+---------+"
+    "| disk | 7     | -       |"
+    "+------+-------+---------+"
+);
+
+test_table!(
+    sparkline_constant_series,
+    Table::new(["3 3 3"]).with(Modify::new(Rows::new(1..)).with(Sparkline::new())),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| ▁▁▁  |"
+    "+------+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    bar_threshold_color,
+    {
+        use tabled::color::Color;
+
+        Table::new(["90", "60", "10"]).with(
+            Modify::new(Rows::new(1..)).with(
+                Bar::percent()
+                    .width(6)
+                    .threshold(50.0, Color::FG_YELLOW)
+                    .threshold(80.0, Color::FG_RED),
+            ),
+        )
+    },
+    "+--------+"
+    "| &str   |"
+    "+--------+"
+    "| \u{1b}[31m█▊\u{1b}[39m 90% |"
+    "+--------+"
+    "| \u{1b}[33m█▎\u{1b}[39m 60% |"
+    "+--------+"
+    "| ▎  10% |"
+    "+--------+"
+);
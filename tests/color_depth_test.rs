@@ -0,0 +1,146 @@
+use tabled::color_depth::ColorDepth;
+
+#[cfg(feature = "color")]
+use crate::util::static_table;
+#[cfg(feature = "color")]
+use tabled::{
+    rich::{RichText, TextColor, TextStyle},
+    Style, Table,
+};
+
+mod util;
+
+#[cfg(feature = "color")]
+fn rich_table() -> Table {
+    let text = RichText::styled(
+        "Hello",
+        TextStyle::new().bold().fg(TextColor::Rgb(0, 190, 190)),
+    );
+
+    let mut table = Table::new([text.to_ansi()]);
+    table.with(Style::psql());
+    table
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_depth_true_color_keeps_colors() {
+    let table = rich_table().with(ColorDepth::TrueColor).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " String "
+            "--------"
+            " \u{1b}[1;38;2;0;190;190mHello\u{1b}[0m  "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_depth_ansi256() {
+    let table = rich_table().with(ColorDepth::Ansi256).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " String "
+            "--------"
+            " \u{1b}[1;38;5;37mHello\u{1b}[0m  "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_depth_ansi16() {
+    let table = rich_table().with(ColorDepth::Ansi16).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " String "
+            "--------"
+            " \u{1b}[1;36mHello\u{1b}[0m  "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_depth_no_color() {
+    let table = rich_table().with(ColorDepth::NoColor).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " String "
+            "--------"
+            " Hello  "
+        )
+    );
+}
+
+#[test]
+fn color_depth_detect() {
+    let detect = |vars: &[(&str, &str)]| {
+        ColorDepth::detect(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    };
+
+    assert_eq!(detect(&[]), ColorDepth::Ansi16);
+    assert_eq!(
+        detect(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]),
+        ColorDepth::NoColor
+    );
+    assert_eq!(
+        detect(&[("NO_COLOR", ""), ("COLORTERM", "truecolor")]),
+        ColorDepth::TrueColor
+    );
+    assert_eq!(detect(&[("CLICOLOR", "0")]), ColorDepth::NoColor);
+    assert_eq!(
+        detect(&[("CLICOLOR", "0"), ("CLICOLOR_FORCE", "1")]),
+        ColorDepth::Ansi16
+    );
+    assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorDepth::Ansi256);
+    assert_eq!(detect(&[("TERM", "dumb")]), ColorDepth::NoColor);
+    assert_eq!(
+        detect(&[("TERM", "dumb"), ("CLICOLOR_FORCE", "1")]),
+        ColorDepth::Ansi16
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_depth_border_color() {
+    use tabled::color::Color;
+
+    let mut table = Table::new([1]);
+    table
+        .with(Style::ascii())
+        .with(Color::fg(TextColor::Fixed(196)));
+
+    let expected = static_table!(
+        "\u{1b}[91m+-----+\u{1b}[39m"
+        "\u{1b}[91m|\u{1b}[39m i32 \u{1b}[91m|\u{1b}[39m"
+        "\u{1b}[91m+-----+\u{1b}[39m"
+        "\u{1b}[91m|\u{1b}[39m 1   \u{1b}[91m|\u{1b}[39m"
+        "\u{1b}[91m+-----+\u{1b}[39m"
+    );
+
+    assert_eq!(table.clone().with(ColorDepth::Ansi16).to_string(), expected);
+    assert_eq!(
+        table.clone().with(ColorDepth::NoColor).to_string(),
+        static_table!(
+            "+-----+"
+            "| i32 |"
+            "+-----+"
+            "| 1   |"
+            "+-----+"
+        )
+    );
+}
@@ -0,0 +1,183 @@
+use tabled::{
+    conditional::{Matching, Number, Text},
+    object::{Columns, Rows, Segment},
+    Alignment, Border, Highlight, Modify, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn scores() -> Table {
+    let data = [
+        ("Alice", 95, "passed"),
+        ("Bob", 70, "passed"),
+        ("Eve", 40, "failed"),
+        ("Mallory", 100, "passed"),
+    ];
+
+    Table::builder(data)
+        .set_columns(["name", "score", "status"])
+        .clone()
+        .build()
+}
+
+test_table!(
+    conditional_number_alignment,
+    scores()
+        .with(Style::psql())
+        .with(Modify::new(Matching::new(Columns::single(1), Number::ge(90.0))).with(Alignment::right())),
+    " name    | score | status "
+    "---------+-------+--------"
+    " Alice   |    95 | passed "
+    " Bob     | 70    | passed "
+    " Eve     | 40    | failed "
+    " Mallory |   100 | passed "
+);
+
+test_table!(
+    conditional_ignores_not_numbers,
+    scores()
+        .with(Style::psql())
+        .with(Modify::new(Matching::new(Segment::all(), Number::lt(1000.0))).with(Alignment::right())),
+    " name    | score | status "
+    "---------+-------+--------"
+    " Alice   |    95 | passed "
+    " Bob     |    70 | passed "
+    " Eve     |    40 | failed "
+    " Mallory |   100 | passed "
+);
+
+test_table!(
+    conditional_text_highlight,
+    scores()
+        .with(Style::psql())
+        .with(Highlight::new(Matching::new(Rows::new(1..), Text::equals("failed")), Border::filled('*'))),
+    " name    | score | status  "
+    "---------+-------+-------- "
+    " Alice   | 95    | passed  "
+    " Bob     | 70    | passed  "
+    "                 **********"
+    " Eve     | 40    * failed *"
+    "                 **********"
+    " Mallory | 100   | passed  "
+);
+
+test_table!(
+    conditional_closure,
+    scores()
+        .with(Style::psql())
+        .with(
+            Modify::new(Matching::new(Columns::single(0), |text: &str| text.len() < 4))
+                .with(Alignment::center())
+        ),
+    " name    | score | status "
+    "---------+-------+--------"
+    " Alice   | 95    | passed "
+    "   Bob   | 70    | passed "
+    "   Eve   | 40    | failed "
+    " Mallory | 100   | passed "
+);
+
+#[cfg(feature = "color")]
+mod color {
+    use tabled::{
+        conditional::{ColorScale, Matching, Number, Text},
+        object::{Columns, Object, Rows},
+        rich::{TextColor, TextStyle},
+        Modify, Style,
+    };
+
+    use crate::util::test_table;
+
+    use super::scores;
+
+    test_table!(
+        conditional_color_scale,
+        scores()
+            .with(Style::psql())
+            .with(Modify::new(Columns::single(1)).with(ColorScale::new(TextColor::Rgb(0, 0, 0), TextColor::Rgb(240, 0, 0)))),
+        " name    | score | status "
+        "---------+-------+--------"
+        " Alice   | \u{1b}[38;2;220;0;0m95\u{1b}[39m    | passed "
+        " Bob     | \u{1b}[38;2;120;0;0m70\u{1b}[39m    | passed "
+        " Eve     | \u{1b}[38;2;0;0;0m40\u{1b}[39m    | failed "
+        " Mallory | \u{1b}[38;2;240;0;0m100\u{1b}[39m   | passed "
+    );
+
+    test_table!(
+        conditional_color_scale_range_background,
+        scores()
+            .with(Style::psql())
+            .with(
+                Modify::new(Columns::single(1))
+                    .with(ColorScale::new(TextColor::Black, TextColor::Rgb(0, 0, 200)).range(0.0, 200.0).background())
+            ),
+        " name    | score | status "
+        "---------+-------+--------"
+        " Alice   | \u{1b}[48;2;0;0;95m95\u{1b}[49m    | passed "
+        " Bob     | \u{1b}[48;2;0;0;70m70\u{1b}[49m    | passed "
+        " Eve     | \u{1b}[48;2;0;0;40m40\u{1b}[49m    | failed "
+        " Mallory | \u{1b}[48;2;0;0;100m100\u{1b}[49m   | passed "
+    );
+
+    test_table!(
+        conditional_color_scale_not_header,
+        scores()
+            .with(Style::psql())
+            .with(
+                Modify::new(Columns::single(1).not(Rows::first()))
+                    .with(ColorScale::new(TextColor::Rgb(0, 0, 0), TextColor::Rgb(240, 0, 0)))
+            ),
+        " name    | score | status "
+        "---------+-------+--------"
+        " Alice   | \u{1b}[38;2;220;0;0m95\u{1b}[39m    | passed "
+        " Bob     | \u{1b}[38;2;120;0;0m70\u{1b}[39m    | passed "
+        " Eve     | \u{1b}[38;2;0;0;0m40\u{1b}[39m    | failed "
+        " Mallory | \u{1b}[38;2;240;0;0m100\u{1b}[39m   | passed "
+    );
+
+    test_table!(
+        conditional_color_scale_matching,
+        scores()
+            .with(Style::psql())
+            .with(
+                Modify::new(Matching::new(Columns::single(1), Number::lt(100.0)))
+                    .with(ColorScale::new(TextColor::Rgb(0, 0, 0), TextColor::Rgb(0, 220, 0)))
+            ),
+        " name    | score | status "
+        "---------+-------+--------"
+        " Alice   | \u{1b}[38;2;0;220;0m95\u{1b}[39m    | passed "
+        " Bob     | \u{1b}[38;2;0;120;0m70\u{1b}[39m    | passed "
+        " Eve     | \u{1b}[38;2;0;0;0m40\u{1b}[39m    | failed "
+        " Mallory | 100   | passed "
+    );
+
+    test_table!(
+        conditional_color_scale_twice,
+        scores()
+            .with(Style::psql())
+            .with(Modify::new(Columns::single(1)).with(ColorScale::new(TextColor::Rgb(0, 0, 0), TextColor::Rgb(240, 0, 0))))
+            .with(Modify::new(Columns::single(1)).with(ColorScale::new(TextColor::Rgb(0, 0, 0), TextColor::Rgb(0, 0, 240))))
+            .with(Modify::new(Columns::single(1)).with(ColorScale::new(TextColor::Black, TextColor::Rgb(0, 0, 200)).range(0.0, 200.0).background())),
+        " name    | score | status "
+        "---------+-------+--------"
+        " Alice   | \u{1b}[48;2;0;0;95m\u{1b}[38;2;0;0;220m95\u{1b}[39m\u{1b}[49m    | passed "
+        " Bob     | \u{1b}[48;2;0;0;70m\u{1b}[38;2;0;0;120m70\u{1b}[39m\u{1b}[49m    | passed "
+        " Eve     | \u{1b}[48;2;0;0;40m\u{1b}[38;2;0;0;0m40\u{1b}[39m\u{1b}[49m    | failed "
+        " Mallory | \u{1b}[48;2;0;0;100m\u{1b}[38;2;0;0;240m100\u{1b}[39m\u{1b}[49m   | passed "
+    );
+
+    test_table!(
+        conditional_text_style,
+        scores()
+            .with(Style::psql())
+            .with(Modify::new(Matching::new(Rows::new(1..), Text::contains("fail"))).with(TextStyle::new().bold())),
+        " name    | score | status "
+        "---------+-------+--------"
+        " Alice   | 95    | passed "
+        " Bob     | 70    | passed "
+        " Eve     | 40    | \u{1b}[1mfailed\u{1b}[0m "
+        " Mallory | 100   | passed "
+    );
+}
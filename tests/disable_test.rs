@@ -19,6 +19,20 @@ test_table!(
     "+---+----------+----------+----------+"
 );
 
+test_table!(
+    disable_rows_unbounded_end,
+    create_table::<5, 1>().with(Disable::row(Rows::new(1..))),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+);
+
+test_table!(
+    disable_rows_unbounded,
+    create_table::<5, 1>().with(Disable::row(Rows::new(..))),
+    ""
+);
+
 test_table!(
     disable_header,
     create_table::<3, 3>().with(Style::psql()).with(Disable::row(Rows::first())),
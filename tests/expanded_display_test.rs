@@ -1,5 +1,9 @@
 use crate::util::{create_vector, static_table};
-use tabled::{display::ExpandedDisplay, Tabled};
+use tabled::{
+    display::ExpandedDisplay,
+    object::{Columns, Segment},
+    Modify, Padding, Style, Tabled, Width,
+};
 
 #[cfg(feature = "color")]
 use owo_colors::{AnsiColors, OwoColorize};
@@ -56,6 +60,23 @@ fn display() {
     );
 }
 
+#[test]
+fn display_keep_trailing_spaces() {
+    let table = ExpandedDisplay::new(create_vector::<1, 2>())
+        .keep_trailing_spaces(true)
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 0 ]-"
+            "N        | 0  "
+            "column 0 | 0-0"
+            "column 1 | 0-1"
+        )
+    );
+}
+
 #[test]
 fn display_empty_records() {
     build_tabled_type!(TestType, 3, ["He", "123", "asd"], ["1", "2", "3"]);
@@ -489,3 +510,133 @@ fn display_with_truncate_colored() {
         )
     );
 }
+
+#[test]
+fn display_raw_multiline_value() {
+    let mut data = create_vector::<2, 2>();
+    data[0][1] = "Hello\nWorld".to_string();
+
+    let table = ExpandedDisplay::raw(&data).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 0 ]---"
+            "N        | 0"
+            "column 0 | Hello"
+            "         | World"
+            "column 1 | 0-1"
+            "-[ RECORD 1 ]---"
+            "N        | 1"
+            "column 0 | 1-0"
+            "column 1 | 1-1"
+        )
+    );
+}
+
+#[test]
+fn display_custom_header() {
+    let data = create_vector::<2, 1>();
+
+    let table = ExpandedDisplay::new(&data)
+        .header(|i| format!("-[ ROW #{} ]", i + 1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ ROW #1 ]---"
+            "N        | 0"
+            "column 0 | 0-0"
+            "-[ ROW #2 ]---"
+            "N        | 1"
+            "column 0 | 1-0"
+        )
+    );
+}
+
+#[test]
+fn display_select() {
+    let data = create_vector::<4, 1>();
+
+    let table = ExpandedDisplay::new(&data).select([3, 1, 10]).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 1 ]-"
+            "N        | 1"
+            "column 0 | 1-0"
+            "-[ RECORD 3 ]-"
+            "N        | 3"
+            "column 0 | 3-0"
+        )
+    );
+
+    let table = ExpandedDisplay::new(&data).select([]).to_string();
+    assert_eq!(table, "");
+}
+
+#[test]
+fn display_with_wrap() {
+    let mut data = create_vector::<1, 1>();
+    data[0][1] = String::from("a long string");
+
+    let table = ExpandedDisplay::new(&data)
+        .with(Modify::new(Columns::single(1)).with(Width::wrap(5)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 0 ]---"
+            "N        | 0"
+            "column 0 | a lon"
+            "         | g str"
+            "         | ing"
+        )
+    );
+}
+
+#[test]
+fn display_with_style() {
+    let data = create_vector::<2, 1>();
+
+    let table = ExpandedDisplay::new(&data)
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Padding::new(1, 1, 0, 0)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 0 ]-───┐"
+            "│ N        │ 0   │"
+            "├──────────┼─────┤"
+            "│ column 0 │ 0-0 │"
+            "-[ RECORD 1 ]-───┤"
+            "│ N        │ 1   │"
+            "├──────────┼─────┤"
+            "│ column 0 │ 1-0 │"
+            "└──────────┴─────┘"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn display_raw_colored() {
+    let mut data = create_vector::<1, 1>();
+    data[0][1] = "https://getfedora.org/".red().to_string();
+
+    let table = ExpandedDisplay::raw(&data).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 0 ]--------------------"
+            "N        | 0"
+            "column 0 | \u{1b}[31mhttps://getfedora.org/\u{1b}[39m"
+        )
+    );
+}
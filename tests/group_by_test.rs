@@ -0,0 +1,202 @@
+use tabled::{
+    builder::Builder,
+    group_by::{Aggregate, GroupBy},
+    object::{Cell, Rows},
+    Alignment, Modify, Padding, Span, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn sales() -> Table {
+    let data = [
+        ("EU", "Berlin", "Q1", 10),
+        ("US", "Boston", "Q1", 20),
+        ("EU", "Paris", "Q2", 5),
+        ("EU", "Berlin", "Q2", 15),
+        ("US", "Boston", "Q2", 30),
+    ];
+
+    Table::builder(data)
+        .set_columns(["region", "city", "quarter", "amount"])
+        .clone()
+        .build()
+}
+
+test_table!(
+    group_by_single_column,
+    sales().with(Style::psql()).with(GroupBy::new([0])),
+    " region | city   | quarter | amount "
+    "--------+--------+---------+--------"
+    " EU                                 "
+    " EU     | Berlin | Q1      | 10     "
+    " EU     | Paris  | Q2      | 5      "
+    " EU     | Berlin | Q2      | 15     "
+    " US                                 "
+    " US     | Boston | Q1      | 20     "
+    " US     | Boston | Q2      | 30     "
+);
+
+test_table!(
+    group_by_multiple_columns_with_header,
+    sales()
+        .with(Style::psql())
+        .with(GroupBy::new([0, 1]).header(|values| format!("== {} ==", values.join(" / ")))),
+    " region | city   | quarter | amount "
+    "--------+--------+---------+--------"
+    " == EU / Berlin ==                  "
+    " EU     | Berlin | Q1      | 10     "
+    " EU     | Berlin | Q2      | 15     "
+    " == US / Boston ==                  "
+    " US     | Boston | Q1      | 20     "
+    " US     | Boston | Q2      | 30     "
+    " == EU / Paris ==                   "
+    " EU     | Paris  | Q2      | 5      "
+);
+
+test_table!(
+    group_by_subtotals_and_total,
+    sales()
+        .with(Style::psql())
+        .with(
+            GroupBy::new([2])
+                .aggregate(3, Aggregate::Sum)
+                .aggregate(1, Aggregate::Count)
+                .subtotals()
+                .total()
+        ),
+    " region   | city   | quarter | amount "
+    "----------+--------+---------+--------"
+    " Q1                                   "
+    " EU       | Berlin | Q1      | 10     "
+    " US       | Boston | Q1      | 20     "
+    " Subtotal | 2      |         | 30     "
+    " Q2                                   "
+    " EU       | Paris  | Q2      | 5      "
+    " EU       | Berlin | Q2      | 15     "
+    " US       | Boston | Q2      | 30     "
+    " Subtotal | 3      |         | 50     "
+    " Total    | 5      |         | 80     "
+);
+
+test_table!(
+    group_by_total_with_labels,
+    sales()
+        .with(Style::psql())
+        .with(
+            GroupBy::new([0])
+                .aggregate(3, Aggregate::Mean)
+                .aggregate(0, Aggregate::Max)
+                .labels("-", "avg")
+                .total()
+        ),
+    " region | city   | quarter | amount "
+    "--------+--------+---------+--------"
+    " EU                                 "
+    " EU     | Berlin | Q1      | 10     "
+    " EU     | Paris  | Q2      | 5      "
+    " EU     | Berlin | Q2      | 15     "
+    " US                                 "
+    " US     | Boston | Q1      | 20     "
+    " US     | Boston | Q2      | 30     "
+    "        | avg    |         | 16     "
+);
+
+test_table!(
+    group_by_keeps_header_groups,
+    {
+        let mut builder = Table::builder([("EU", 10), ("US", 20), ("EU", 5)]);
+        builder.set_columns(["region", "amount"]);
+        builder.set_header_groups([[("sales", 2)]]);
+
+        let mut table = builder.build();
+        table.with(Style::psql()).with(GroupBy::new([0]));
+        table
+    },
+    " sales           "
+    "--------+--------"
+    " region | amount "
+    " EU              "
+    " EU     | 10     "
+    " EU     | 5      "
+    " US              "
+    " US     | 20     "
+);
+
+test_table!(
+    group_by_without_header,
+    {
+        let mut builder = Builder::default();
+        builder.add_record(["a", "1"]);
+        builder.add_record(["b", "2"]);
+        builder.add_record(["a", "3"]);
+
+        let mut table = builder.build();
+        table
+            .with(Style::psql())
+            .with(GroupBy::new([0]).aggregate(1, Aggregate::Min).subtotals());
+        table
+    },
+    " a            "
+    "----------+---"
+    " a        | 1 "
+    " a        | 3 "
+    " Subtotal | 1 "
+    " b            "
+    " b        | 2 "
+    " Subtotal | 2 "
+);
+
+test_table!(
+    group_by_out_of_bounds_column,
+    sales().with(Style::psql()).with(GroupBy::new([10])),
+    " region | city   | quarter | amount "
+    "--------+--------+---------+--------"
+    " EU     | Berlin | Q1      | 10     "
+    " US     | Boston | Q1      | 20     "
+    " EU     | Paris  | Q2      | 5      "
+    " EU     | Berlin | Q2      | 15     "
+    " US     | Boston | Q2      | 30     "
+);
+
+test_table!(
+    group_by_moves_cell_settings,
+    sales()
+        .with(Style::psql())
+        .with(Modify::new(Rows::single(2)).with(Alignment::right()))
+        .with(Modify::new(Cell(3, 1)).with(Padding::new(3, 1, 0, 0)))
+        .with(GroupBy::new([0]).aggregate(3, Aggregate::Sum).subtotals()),
+    " region   | city    | quarter | amount "
+    "----------+---------+---------+--------"
+    " EU                                    "
+    " EU       | Berlin  | Q1      | 10     "
+    " EU       |   Paris | Q2      | 5      "
+    " EU       | Berlin  | Q2      | 15     "
+    " Subtotal |         |         | 30     "
+    " US                                    "
+    "       US |  Boston |      Q1 |     20 "
+    " US       | Boston  | Q2      | 30     "
+    " Subtotal |         |         | 50     "
+);
+
+test_table!(
+    group_by_clears_spans,
+    sales()
+        .with(Style::psql())
+        .with(Modify::new(Cell(2, 1)).with(Span::column(2)))
+        .with(GroupBy::new([0]))
+        .with(GroupBy::new([2])),
+    " region | city   | quarter | amount "
+    "--------+--------+---------+--------"
+    "                                    "
+    " EU     |        |         |        "
+    " US     |        |         |        "
+    " Q1                                 "
+    " EU     | Berlin | Q1      | 10     "
+    " US     | Boston | Q1      | 20     "
+    " Q2                                 "
+    " EU     | Paris  | Q2      | 5      "
+    " EU     | Berlin | Q2      | 15     "
+    " US     | Boston | Q2      | 30     "
+);
@@ -0,0 +1,286 @@
+use tabled::{builder::Builder, Style, Table};
+
+use crate::util::{static_table, test_table};
+
+mod util;
+
+fn quarters() -> Builder<'static> {
+    let mut builder = Builder::default();
+    builder
+        .set_header_groups([[("", 1), ("Q1", 3), ("Q2", 3)]])
+        .set_columns(["city", "Jan", "Feb", "Mar", "Apr", "May", "Jun"])
+        .add_record(["Berlin", "1", "2", "3", "4", "5", "6"]);
+    builder
+}
+
+test_table!(
+    builder_header_groups,
+    quarters().build().with(Style::modern()),
+    "┌────────┬─────┬─────┬─────┬─────┬─────┬─────┐"
+    "│        │ Q1              │ Q2              │"
+    "├────────┼─────┼─────┼─────┼─────┼─────┼─────┤"
+    "│ city   │ Jan │ Feb │ Mar │ Apr │ May │ Jun │"
+    "├────────┼─────┼─────┼─────┼─────┼─────┼─────┤"
+    "│ Berlin │ 1   │ 2   │ 3   │ 4   │ 5   │ 6   │"
+    "└────────┴─────┴─────┴─────┴─────┴─────┴─────┘"
+);
+
+test_table!(
+    builder_header_groups_levels,
+    {
+        let mut builder = quarters();
+        builder.set_header_groups([
+            vec![("", 1), ("H1", 6)],
+            vec![("", 1), ("Q1", 3), ("Q2", 3)],
+        ]);
+        builder.build().with(Style::ascii()).to_string()
+    },
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "|        | H1                                |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "|        | Q1              | Q2              |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "| city   | Jan | Feb | Mar | Apr | May | Jun |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "| Berlin | 1   | 2   | 3   | 4   | 5   | 6   |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+);
+
+test_table!(
+    builder_header_groups_are_cut,
+    {
+        let mut builder = Builder::default();
+        builder
+            .set_header_groups([[("group", 10)]])
+            .set_columns(["a", "b"])
+            .add_record(["1", "2"]);
+        builder.build().with(Style::ascii()).to_string()
+    },
+    "+---+---+"
+    "| group |"
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
+
+test_table!(
+    builder_header_groups_with_index,
+    {
+        let mut builder = quarters().index();
+        builder.set_index(0).set_name(None);
+        builder.build().with(Style::ascii()).to_string()
+    },
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "|        | Q1              | Q2              |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "|        | Jan | Feb | Mar | Apr | May | Jun |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+    "| Berlin | 1   | 2   | 3   | 4   | 5   | 6   |"
+    "+--------+-----+-----+-----+-----+-----+-----+"
+);
+
+test_table!(
+    builder_header_groups_removed_column,
+    {
+        let mut builder = quarters().index();
+        builder.hide_index().set_index(2);
+        builder.build().with(Style::ascii()).to_string()
+    },
+    "+--------+-----+-----+-----+-----+-----+"
+    "|        | Q1        | Q2              |"
+    "+--------+-----+-----+-----+-----+-----+"
+    "| city   | Jan | Mar | Apr | May | Jun |"
+    "+--------+-----+-----+-----+-----+-----+"
+    "| Feb    |     |     |     |     |     |"
+    "+--------+-----+-----+-----+-----+-----+"
+    "| Berlin | 1   | 3   | 4   | 5   | 6   |"
+    "+--------+-----+-----+-----+-----+-----+"
+);
+
+#[cfg(feature = "derive")]
+mod derived {
+    use tabled::Tabled;
+
+    use super::*;
+
+    #[derive(Tabled)]
+    struct Quarter {
+        first: u8,
+        second: u8,
+        third: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Year {
+        #[tabled(inline, group = "H1")]
+        half: Half,
+        #[tabled(inline, group = "Q3")]
+        q3: Quarter,
+        total: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Half {
+        #[tabled(inline, group = "Q1")]
+        q1: Quarter,
+        #[tabled(inline, group = "Q2")]
+        q2: Quarter,
+    }
+
+    fn year() -> Year {
+        let quarter = |first| Quarter {
+            first,
+            second: first + 1,
+            third: first + 2,
+        };
+
+        Year {
+            half: Half {
+                q1: quarter(1),
+                q2: quarter(4),
+            },
+            q3: quarter(7),
+            total: 45,
+        }
+    }
+
+    #[test]
+    fn derive_header_groups() {
+        assert_eq!(
+            Year::header_groups(),
+            [
+                vec![("H1".into(), 6), ("".into(), 3), ("".into(), 1)],
+                vec![
+                    ("Q1".into(), 3),
+                    ("Q2".into(), 3),
+                    ("Q3".into(), 3),
+                    ("".into(), 1)
+                ],
+            ]
+        );
+        assert_eq!(Quarter::header_groups(), Vec::<Vec<_>>::new());
+        assert_eq!(<(Quarter, Year)>::header_groups().len(), 2);
+    }
+
+    #[test]
+    fn derive_without_header_groups() {
+        #[derive(Tabled)]
+        struct Wrapper {
+            #[tabled(inline)]
+            quarter: Quarter,
+            total: u8,
+        }
+
+        #[derive(Tabled)]
+        enum Period {
+            Day,
+            #[tabled(inline)]
+            Month(u8),
+        }
+
+        assert_eq!(Wrapper::header_groups(), Vec::<Vec<_>>::new());
+        assert_eq!(Period::header_groups(), Vec::<Vec<_>>::new());
+        assert_eq!(
+            Table::new([Period::Day, Period::Month(1)]).count_header_groups(),
+            0
+        );
+    }
+
+    #[test]
+    fn derive_header_groups_count() {
+        let table = Table::new([year()]);
+        assert!(table.has_header());
+        assert_eq!(table.count_header_groups(), 2);
+
+        let table = Table::builder([year()]).build();
+        assert!(table.has_header());
+        assert_eq!(table.count_header_groups(), 2);
+
+        let table = Table::new([Quarter {
+            first: 1,
+            second: 2,
+            third: 3,
+        }]);
+        assert_eq!(table.count_header_groups(), 0);
+    }
+
+    test_table!(
+        derive_nested_header_groups,
+        Table::new([year()]).with(Style::ascii()),
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| H1                                              |                        |       |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| Q1                     | Q2                     | Q3                     |       |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| first | second | third | first | second | third | first | second | third | total |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| 1     | 2      | 3     | 4     | 5      | 6     | 7     | 8      | 9     | 45    |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+    );
+
+    test_table!(
+        derive_header_groups_builder,
+        Table::builder([year()]).build().with(Style::ascii()),
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| H1                                              |                        |       |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| Q1                     | Q2                     | Q3                     |       |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| first | second | third | first | second | third | first | second | third | total |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+        "| 1     | 2      | 3     | 4     | 5      | 6     | 7     | 8      | 9     | 45    |"
+        "+-------+--------+-------+-------+--------+-------+-------+--------+-------+-------+"
+    );
+
+    #[derive(Tabled)]
+    enum Shape {
+        #[tabled(inline, group = "circle")]
+        Circle { radius: u8 },
+        #[tabled(inline("rect "), group = "rectangle")]
+        Rectangle { width: u8, height: u8 },
+    }
+
+    test_table!(
+        derive_enum_header_groups,
+        Table::new([Shape::Circle { radius: 1 }, Shape::Rectangle { width: 2, height: 3 }])
+            .with(Style::ascii()),
+        "+--------+------------+-------------+"
+        "| circle | rectangle                |"
+        "+--------+------------+-------------+"
+        "| radius | rect width | rect height |"
+        "+--------+------------+-------------+"
+        "| 1      |            |             |"
+        "+--------+------------+-------------+"
+        "|        | 2          | 3           |"
+        "+--------+------------+-------------+"
+    );
+
+    #[test]
+    fn derive_single_column_group() {
+        #[derive(Tabled)]
+        struct Item {
+            #[tabled(group = "id")]
+            key: u8,
+            name: &'static str,
+        }
+
+        let table = Table::new([Item { key: 1, name: "a" }])
+            .with(Style::ascii())
+            .to_string();
+
+        assert_eq!(
+            table,
+            static_table!(
+                "+-----+------+"
+                "| id  |      |"
+                "+-----+------+"
+                "| key | name |"
+                "+-----+------+"
+                "| 1   | a    |"
+                "+-----+------+"
+            )
+        );
+    }
+}
@@ -195,3 +195,113 @@ fn builder_index_no_name_transpose_transpose() {
 
     assert_eq!(orig_table, two_times_transposed_table,);
 }
+
+fn sales() -> Builder<'static> {
+    let mut builder = Builder::default();
+    builder.set_columns(["region", "city", "year", "amount"]);
+    builder.add_record(["EU", "Berlin", "2021", "10"]);
+    builder.add_record(["EU", "Berlin", "2022", "15"]);
+    builder.add_record(["EU", "Paris", "2022", "5"]);
+    builder.add_record(["US", "Boston", "2021", "20"]);
+    builder
+}
+
+test_table!(
+    builder_multi_index,
+    sales().index().set_multi_index([0, 1]).clone().build(),
+    "+--------+--------+------+--------+"
+    "|        |        | year | amount |"
+    "+--------+--------+------+--------+"
+    "| region | city   |      |        |"
+    "+--------+--------+------+--------+"
+    "| EU     | Berlin | 2021 | 10     |"
+    "+        +--------+------+--------+"
+    "|        | Berlin | 2022 | 15     |"
+    "+        +--------+------+--------+"
+    "|        | Paris  | 2022 | 5      |"
+    "+--------+--------+------+--------+"
+    "| US     | Boston | 2021 | 20     |"
+    "+--------+--------+------+--------+"
+);
+
+test_table!(
+    builder_multi_index_three_levels,
+    sales().index().set_multi_index([0, 1, 2]).set_name(None).clone().build(),
+    "+----+--------+------+--------+"
+    "|    |        |      | amount |"
+    "+----+--------+------+--------+"
+    "| EU | Berlin | 2021 | 10     |"
+    "+    +        +------+--------+"
+    "|    |        | 2022 | 15     |"
+    "+    +--------+------+--------+"
+    "|    | Paris  | 2022 | 5      |"
+    "+----+--------+------+--------+"
+    "| US | Boston | 2021 | 20     |"
+    "+----+--------+------+--------+"
+);
+
+test_table!(
+    builder_multi_index_order,
+    sales().index().set_multi_index([1, 0]).set_names(["City", "Region"]).clone().build(),
+    "+--------+--------+------+--------+"
+    "|        |        | year | amount |"
+    "+--------+--------+------+--------+"
+    "| City   | Region |      |        |"
+    "+--------+--------+------+--------+"
+    "| Berlin | EU     | 2021 | 10     |"
+    "+        +--------+------+--------+"
+    "|        | EU     | 2022 | 15     |"
+    "+--------+--------+------+--------+"
+    "| Paris  | EU     | 2022 | 5      |"
+    "+--------+--------+------+--------+"
+    "| Boston | US     | 2021 | 20     |"
+    "+--------+--------+------+--------+"
+);
+
+test_table!(
+    builder_multi_index_transpose,
+    sales().index().set_multi_index([0, 1]).transpose().clone().build(),
+    "+--------+--------+--------+-------+--------+"
+    "| region | EU                      | US     |"
+    "+--------+--------+--------+-------+--------+"
+    "| city   | Berlin | Berlin | Paris | Boston |"
+    "+--------+--------+--------+-------+--------+"
+    "| year   | 2021   | 2022   | 2022  | 2021   |"
+    "+--------+--------+--------+-------+--------+"
+    "| amount | 10     | 15     | 5     | 20     |"
+    "+--------+--------+--------+-------+--------+"
+);
+
+test_table!(
+    builder_multi_index_transpose_twice,
+    sales().index().set_multi_index([0, 1]).transpose().transpose().clone().build(),
+    "+--------+--------+------+--------+"
+    "|        |        | year | amount |"
+    "+--------+--------+------+--------+"
+    "| region | city   |      |        |"
+    "+--------+--------+------+--------+"
+    "| EU     | Berlin | 2021 | 10     |"
+    "+        +--------+------+--------+"
+    "|        | Berlin | 2022 | 15     |"
+    "+        +--------+------+--------+"
+    "|        | Paris  | 2022 | 5      |"
+    "+--------+--------+------+--------+"
+    "| US     | Boston | 2021 | 20     |"
+    "+--------+--------+------+--------+"
+);
+
+test_table!(
+    builder_multi_index_invalid_columns,
+    sales().index().set_multi_index([0, 0]).set_multi_index([1, 10]).hide_index().clone().build(),
+    "+--------+--------+------+--------+"
+    "| region | city   | year | amount |"
+    "+--------+--------+------+--------+"
+    "| EU     | Berlin | 2021 | 10     |"
+    "+--------+--------+------+--------+"
+    "| EU     | Berlin | 2022 | 15     |"
+    "+--------+--------+------+--------+"
+    "| EU     | Paris  | 2022 | 5      |"
+    "+--------+--------+------+--------+"
+    "| US     | Boston | 2021 | 20     |"
+    "+--------+--------+------+--------+"
+);
@@ -0,0 +1,112 @@
+use tabled::{
+    locator::ByColumnName,
+    object::{Columns, Segment},
+    Join, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn users() -> Table {
+    let mut builder = Table::builder([(1, "Alice", "EU"), (2, "Bob", "US"), (3, "Eve", "EU")]);
+    builder.set_columns(["id", "name", "region"]);
+    builder.build()
+}
+
+fn orders() -> Table {
+    let mut builder = Table::builder([
+        (1, "book", "EU"),
+        (3, "cup", "EU"),
+        (1, "pen", "US"),
+        (4, "ink", "US"),
+    ]);
+    builder.set_columns(["id", "item", "region"]);
+    builder.build()
+}
+
+test_table!(
+    join_inner,
+    users().with(Join::inner(orders(), [ByColumnName::new("id")])).with(Style::psql()),
+    " id | name  | region_x | item | region_y "
+    "----+-------+----------+------+----------"
+    " 1  | Alice | EU       | book | EU       "
+    " 1  | Alice | EU       | pen  | US       "
+    " 3  | Eve   | EU       | cup  | EU       "
+);
+
+test_table!(
+    join_left,
+    users()
+        .with(Join::left(orders(), [ByColumnName::new("id")]).default_cell("NULL"))
+        .with(Style::psql()),
+    " id | name  | region_x | item | region_y "
+    "----+-------+----------+------+----------"
+    " 1  | Alice | EU       | book | EU       "
+    " 1  | Alice | EU       | pen  | US       "
+    " 2  | Bob   | US       | NULL | NULL     "
+    " 3  | Eve   | EU       | cup  | EU       "
+);
+
+test_table!(
+    join_outer,
+    users()
+        .with(Join::outer(orders(), [ByColumnName::new("id")]).suffixes("", " (order)"))
+        .with(Style::psql()),
+    " id | name  | region | item | region (order) "
+    "----+-------+--------+------+----------------"
+    " 1  | Alice | EU     | book | EU             "
+    " 1  | Alice | EU     | pen  | US             "
+    " 2  | Bob   | US     |      |                "
+    " 3  | Eve   | EU     | cup  | EU             "
+    " 4  |       |        | ink  | US             "
+);
+
+test_table!(
+    join_multiple_keys,
+    users()
+        .with(Join::outer(orders(), [ByColumnName::new("id"), ByColumnName::new("region")]))
+        .with(Style::psql()),
+    " id | name  | region | item "
+    "----+-------+--------+------"
+    " 1  | Alice | EU     | book "
+    " 2  | Bob   | US     |      "
+    " 3  | Eve   | EU     | cup  "
+    " 1  |       | US     | pen  "
+    " 4  |       | US     | ink  "
+);
+
+test_table!(
+    join_by_columns,
+    users()
+        .with(Join::inner(orders(), [Columns::single(0)]))
+        .with(Style::psql()),
+    " id | name  | region_x | item | region_y "
+    "----+-------+----------+------+----------"
+    " 1  | Alice | EU       | book | EU       "
+    " 1  | Alice | EU       | pen  | US       "
+    " 3  | Eve   | EU       | cup  | EU       "
+);
+
+test_table!(
+    join_missing_key,
+    users().with(Join::inner(orders(), [ByColumnName::new("user")])).with(Style::psql()),
+    " id | name  | region "
+    "----+-------+--------"
+    " 1  | Alice | EU     "
+    " 2  | Bob   | US     "
+    " 3  | Eve   | EU     "
+);
+
+test_table!(
+    join_keeps_settings,
+    users()
+        .with(tabled::Modify::new(Segment::all()).with(tabled::Alignment::right()))
+        .with(Join::inner(orders(), [ByColumnName::new("id")]))
+        .with(Style::psql()),
+    " id |  name | region_x | item | region_y "
+    "----+-------+----------+------+----------"
+    "  1 | Alice |       EU | book |       EU "
+    "  1 | Alice |       EU |  pen |       US "
+    "  3 |   Eve |       EU |  cup |       EU "
+);
@@ -0,0 +1,129 @@
+use tabled::{display::LiveDisplay, Style, Table};
+
+fn progress(jobs: &[(&str, &str)]) -> Table {
+    let mut table = Table::new(jobs);
+    table.with(Style::ascii());
+    table
+}
+
+fn draw(display: &mut LiveDisplay<Vec<u8>>, table: &Table) -> String {
+    display.draw(table).unwrap();
+    String::from_utf8(std::mem::take(display.get_mut())).unwrap()
+}
+
+#[test]
+fn live_display_first_frame() {
+    let mut display = LiveDisplay::new(Vec::new());
+
+    assert_eq!(
+        draw(&mut display, &progress(&[("build", "10%"), ("test", "0%")])),
+        concat!(
+            "+-------+------+\u{1b}[K\n",
+            "| &str  | &str |\u{1b}[K\n",
+            "+-------+------+\u{1b}[K\n",
+            "| build | 10%  |\u{1b}[K\n",
+            "+-------+------+\u{1b}[K\n",
+            "| test  | 0%   |\u{1b}[K\n",
+            "+-------+------+\u{1b}[K\n",
+        )
+    );
+}
+
+#[test]
+fn live_display_redraws_changed_lines() {
+    let mut display = LiveDisplay::new(Vec::new());
+    draw(&mut display, &progress(&[("build", "10%"), ("test", "0%")]));
+
+    assert_eq!(
+        draw(&mut display, &progress(&[("build", "10%"), ("test", "5%")])),
+        concat!("\u{1b}[2A", "| test  | 5%   |\u{1b}[K\n", "\u{1b}[1B")
+    );
+
+    assert_eq!(
+        draw(
+            &mut display,
+            &progress(&[("build", "50%"), ("test", "50%")])
+        ),
+        concat!(
+            "\u{1b}[4A",
+            "| build | 50%  |\u{1b}[K\n",
+            "\u{1b}[1B",
+            "| test  | 50%  |\u{1b}[K\n",
+            "\u{1b}[1B",
+        )
+    );
+}
+
+#[test]
+fn live_display_same_frame() {
+    let mut display = LiveDisplay::new(Vec::new());
+    draw(&mut display, &progress(&[("build", "10%")]));
+
+    assert_eq!(draw(&mut display, &progress(&[("build", "10%")])), "");
+}
+
+#[test]
+fn live_display_keeps_widths() {
+    let mut display = LiveDisplay::new(Vec::new());
+    draw(&mut display, &progress(&[("build", "100%")]));
+
+    assert_eq!(
+        draw(&mut display, &progress(&[("build", "1%")])),
+        concat!("\u{1b}[2A", "| build | 1%   |\u{1b}[K\n", "\u{1b}[1B")
+    );
+
+    display.reset();
+
+    assert_eq!(
+        draw(&mut display, &progress(&[("build", "1%")])),
+        concat!(
+            "+-------+------+\u{1b}[K\n",
+            "| &str  | &str |\u{1b}[K\n",
+            "+-------+------+\u{1b}[K\n",
+            "| build | 1%   |\u{1b}[K\n",
+            "+-------+------+\u{1b}[K\n",
+        )
+    );
+}
+
+#[test]
+fn live_display_grows_and_shrinks() {
+    let mut display = LiveDisplay::new(Vec::new());
+    draw(&mut display, &progress(&[("build", "done")]));
+
+    assert_eq!(
+        draw(
+            &mut display,
+            &progress(&[("build", "done"), ("test", "1%")])
+        ),
+        concat!("| test  | 1%   |\u{1b}[K\n", "+-------+------+\u{1b}[K\n")
+    );
+
+    assert_eq!(
+        draw(&mut display, &progress(&[("test", "2%")])),
+        concat!(
+            "\u{1b}[4A",
+            "| test  | 2%   |\u{1b}[K\n",
+            "\u{1b}[1B",
+            "\u{1b}[J",
+        )
+    );
+}
+
+#[test]
+fn live_display_wider_column() {
+    let mut display = LiveDisplay::new(Vec::new());
+    draw(&mut display, &progress(&[("build", "1%")]));
+
+    assert_eq!(
+        draw(&mut display, &progress(&[("build", "in progress")])),
+        concat!(
+            "\u{1b}[5A",
+            "+-------+-------------+\u{1b}[K\n",
+            "| &str  | &str        |\u{1b}[K\n",
+            "+-------+-------------+\u{1b}[K\n",
+            "| build | in progress |\u{1b}[K\n",
+            "+-------+-------------+\u{1b}[K\n",
+        )
+    );
+}
@@ -1,4 +1,9 @@
-use tabled::{merge::Merge, Table};
+use tabled::{
+    locator::ByColumnName,
+    merge::{Merge, MergeDuplicates},
+    object::{Columns, Rows},
+    Style, Table,
+};
 
 use crate::util::test_table;
 
@@ -194,3 +199,103 @@ test_table!(
     "| 2 | 0     |"
     "+---+---+---+"
 );
+
+fn regions() -> Table {
+    let data = [
+        ("EU", "Berlin", "A"),
+        ("EU", " berlin", "a"),
+        ("US", "Berlin", "a"),
+        ("US", "Boston", "b"),
+    ];
+
+    let mut builder = Table::builder(data);
+    builder.set_columns(["region", "city", "grade"]);
+    builder.build()
+}
+
+test_table!(
+    merge_duplicates_vertical_exact,
+    regions().with(Style::psql()).with(MergeDuplicates::vertical()),
+    " region | city    | grade "
+    "--------+---------+-------"
+    " EU     | Berlin  | A     "
+    "        |  berlin | a     "
+    " US     | Berlin  |       "
+    "        | Boston  | b     "
+);
+
+test_table!(
+    merge_duplicates_vertical_lines,
+    regions()
+        .with(Style::psql())
+        .with(MergeDuplicates::vertical().lines(ByColumnName::new("grade"))),
+    " region | city    | grade "
+    "--------+---------+-------"
+    " EU     | Berlin  | A     "
+    " EU     |  berlin | a     "
+    " US     | Berlin  |       "
+    " US     | Boston  | b     "
+);
+
+test_table!(
+    merge_duplicates_vertical_custom_equality,
+    regions()
+        .with(Style::psql())
+        .with(MergeDuplicates::vertical().lines(Columns::new(1..)).key(|text| text.trim().to_lowercase())),
+    " region | city   | grade "
+    "--------+--------+-------"
+    " EU     | Berlin | A     "
+    " EU     |        |       "
+    " US     |        |       "
+    " US     | Boston | b     "
+);
+
+test_table!(
+    merge_duplicates_vertical_within,
+    regions().with(Style::psql()).with(
+        MergeDuplicates::vertical()
+            .lines(Columns::new(1..))
+            .within(ByColumnName::new("region"))
+            .compare(|a, b| a.trim().eq_ignore_ascii_case(b.trim()))
+    ),
+    " region | city   | grade "
+    "--------+--------+-------"
+    " EU     | Berlin | A     "
+    " EU     |        |       "
+    " US     | Berlin | a     "
+    " US     | Boston | b     "
+);
+
+test_table!(
+    merge_duplicates_horizontal,
+    Table::new([["a", "A", "b"], ["c", "c", " c"]])
+        .with(Style::psql())
+        .with(MergeDuplicates::horizontal().lines(Rows::new(1..)).ignore_case()),
+    " 0 | 1 | 2  "
+    "---+---+----"
+    " a     | b  "
+    " c     |  c "
+);
+
+test_table!(
+    merge_duplicates_horizontal_within,
+    Table::new([["a", "a", "b"], ["x", "x", "x"]])
+        .with(Style::psql())
+        .with(MergeDuplicates::horizontal().lines(Rows::new(2..)).within(Rows::single(1))),
+    " 0 | 1 | 2 "
+    "---+---+---"
+    " a | a | b "
+    " x     | x "
+);
+
+test_table!(
+    merge_duplicates_keeps_spans,
+    Table::new([["a", "a"], ["a", "a"]])
+        .with(Style::psql())
+        .with(MergeDuplicates::horizontal().lines(Rows::single(1)))
+        .with(MergeDuplicates::vertical()),
+    " 0 | 1 "
+    "---+---"
+    " a     "
+    " a | a "
+);
@@ -0,0 +1,291 @@
+use tabled::{
+    object::{Cell, Rows, Segment},
+    Alignment, Modify, Nested, Padding, Span, Style, Table, Width,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn inner() -> Table {
+    let mut builder = Table::builder([("a", 1), ("b", 2)]);
+    builder.set_columns(["char", "number"]);
+    builder.build()
+}
+
+fn outer() -> Table {
+    let mut builder = Table::builder([("table", "", "end"), ("", "a long text value", "")]);
+    builder.set_columns(["name", "value", "note"]);
+    builder.build()
+}
+
+test_table!(
+    nested_table_is_increased_to_cell_width,
+    outer().with(Modify::new(Cell(1, 1)).with(Nested::new(inner()))),
+    "+-------+-------------------+------+"
+    "| name  | value             | note |"
+    "+-------+-------------------+------+"
+    "| table | +------+--------+ | end  |"
+    "|       | | char | number | |      |"
+    "|       | +------+--------+ |      |"
+    "|       | | a    | 1      | |      |"
+    "|       | +------+--------+ |      |"
+    "|       | | b    | 2      | |      |"
+    "|       | +------+--------+ |      |"
+    "+-------+-------------------+------+"
+    "|       | a long text value |      |"
+    "+-------+-------------------+------+"
+);
+
+test_table!(
+    nested_table_increases_column,
+    Table::new([("table", "")]).with(Modify::new(Cell(1, 1)).with(Nested::new(inner()))),
+    "+-------+-------------------+"
+    "| &str  | &str              |"
+    "+-------+-------------------+"
+    "| table | +------+--------+ |"
+    "|       | | char | number | |"
+    "|       | +------+--------+ |"
+    "|       | | a    | 1      | |"
+    "|       | +------+--------+ |"
+    "|       | | b    | 2      | |"
+    "|       | +------+--------+ |"
+    "+-------+-------------------+"
+);
+
+test_table!(
+    nested_table_is_aligned_vertically,
+    outer()
+        .with(Modify::new(Cell(1, 0)).with(String::from("1\n2\n3\n4\n5\n6\n7\n8\n9")))
+        .with(Modify::new(Segment::all()).with(Alignment::center_vertical()))
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner()))),
+    "+------+-------------------+------+"
+    "| name | value             | note |"
+    "+------+-------------------+------+"
+    "| 1    |                   |      |"
+    "| 2    | +------+--------+ |      |"
+    "| 3    | | char | number | |      |"
+    "| 4    | +------+--------+ |      |"
+    "| 5    | | a    | 1      | | end  |"
+    "| 6    | +------+--------+ |      |"
+    "| 7    | | b    | 2      | |      |"
+    "| 8    | +------+--------+ |      |"
+    "| 9    |                   |      |"
+    "+------+-------------------+------+"
+    "|      | a long text value |      |"
+    "+------+-------------------+------+"
+);
+
+test_table!(
+    nested_table_collapse,
+    outer().with(Modify::new(Cell(1, 1)).with(Nested::new(inner()).collapse())),
+    "+-------+-------------------+------+"
+    "| name  | value             | note |"
+    "+-------+--------+----------+------+"
+    "| table | char   | number   | end  |"
+    "|       +--------+----------+      |"
+    "|       | a      | 1        |      |"
+    "|       +--------+----------+      |"
+    "|       | b      | 2        |      |"
+    "+-------+--------+----------+------+"
+    "|       | a long text value |      |"
+    "+-------+-------------------+------+"
+);
+
+test_table!(
+    nested_table_collapse_modern,
+    outer()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner().with(Style::modern()).clone()).collapse())),
+    "┌───────┬───────────────────┬──────┐"
+    "│ name  │ value             │ note │"
+    "├───────┼────────┬──────────┼──────┤"
+    "│ table │ char   │ number   │ end  │"
+    "│       ├────────┼──────────┤      │"
+    "│       │ a      │ 1        │      │"
+    "│       ├────────┼──────────┤      │"
+    "│       │ b      │ 2        │      │"
+    "├───────┼────────┴──────────┼──────┤"
+    "│       │ a long text value │      │"
+    "└───────┴───────────────────┴──────┘"
+);
+
+test_table!(
+    nested_table_collapse_fills_row,
+    outer()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 0)).with(String::from("1\n2\n3\n4\n5\n6\n7\n8\n9")))
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner().with(Style::modern()).clone()).collapse())),
+    "┌──────┬───────────────────┬──────┐"
+    "│ name │ value             │ note │"
+    "├──────┼────────┬──────────┼──────┤"
+    "│ 1    │ char   │ number   │ end  │"
+    "│ 2    │        │          │      │"
+    "│ 3    │        │          │      │"
+    "│ 4    ├────────┼──────────┤      │"
+    "│ 5    │ a      │ 1        │      │"
+    "│ 6    │        │          │      │"
+    "│ 7    ├────────┼──────────┤      │"
+    "│ 8    │ b      │ 2        │      │"
+    "│ 9    │        │          │      │"
+    "├──────┼────────┴──────────┼──────┤"
+    "│      │ a long text value │      │"
+    "└──────┴───────────────────┴──────┘"
+);
+
+test_table!(
+    nested_table_collapse_in_first_and_last_cells,
+    Table::new([("", "")])
+        .with(Style::modern())
+        .with(Modify::new(Cell(0, 0)).with(Nested::new(inner().with(Style::modern()).clone()).collapse()))
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner().with(Style::modern()).clone()).collapse())),
+    "┌──────┬────────┬───────────────┐"
+    "│ char │ number │ &str          │"
+    "├──────┼────────┤               │"
+    "│ a    │ 1      │               │"
+    "├──────┼────────┤               │"
+    "│ b    │ 2      │               │"
+    "├──────┴────────┼──────┬────────┤"
+    "│               │ char │ number │"
+    "│               ├──────┼────────┤"
+    "│               │ a    │ 1      │"
+    "│               ├──────┼────────┤"
+    "│               │ b    │ 2      │"
+    "└───────────────┴──────┴────────┘"
+);
+
+test_table!(
+    nested_table_collapse_in_spanned_cell,
+    outer()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(3)))
+        .with(Style::correct_spans())
+        .with(Modify::new(Cell(1, 0)).with(Nested::new(inner().with(Style::modern()).clone()).collapse())),
+    "┌──────┬───────────────────┬──────┐"
+    "│ name │ value             │ note │"
+    "├──────┴────────┬──────────┴──────┤"
+    "│ char          │ number          │"
+    "├───────────────┼─────────────────┤"
+    "│ a             │ 1               │"
+    "├───────────────┼─────────────────┤"
+    "│ b             │ 2               │"
+    "├──────┬────────┴──────────┬──────┤"
+    "│      │ a long text value │      │"
+    "└──────┴───────────────────┴──────┘"
+);
+
+test_table!(
+    nested_table_collapse_with_spans_inside,
+    outer()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(
+            inner()
+                .with(Style::modern())
+                .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+                .with(Style::correct_spans())
+                .clone(),
+        ).collapse())),
+    "┌───────┬───────────────────┬──────┐"
+    "│ name  │ value             │ note │"
+    "├───────┼───────────────────┼──────┤"
+    "│ table │ char              │ end  │"
+    "│       ├─────────┬─────────┤      │"
+    "│       │ a       │ 1       │      │"
+    "│       ├─────────┼─────────┤      │"
+    "│       │ b       │ 2       │      │"
+    "├───────┼─────────┴─────────┼──────┤"
+    "│       │ a long text value │      │"
+    "└───────┴───────────────────┴──────┘"
+);
+
+test_table!(
+    nested_table_collapse_keeps_increased_width,
+    outer()
+        .with(Style::modern())
+        .with(Width::increase(50))
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner().with(Style::modern()).clone()).collapse())),
+    "┌────────────┬────────────────────────┬──────────┐"
+    "│ name       │ value                  │ note     │"
+    "├────────────┼───────────┬────────────┼──────────┤"
+    "│ table      │ char      │ number     │ end      │"
+    "│            ├───────────┼────────────┤          │"
+    "│            │ a         │ 1          │          │"
+    "│            ├───────────┼────────────┤          │"
+    "│            │ b         │ 2          │          │"
+    "├────────────┼───────────┴────────────┼──────────┤"
+    "│            │ a long text value      │          │"
+    "└────────────┴────────────────────────┴──────────┘"
+);
+
+test_table!(
+    nested_table_collapse_without_frame,
+    outer()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner().with(Style::psql()).clone()).collapse())),
+    "┌───────┬───────────────────┬──────┐"
+    "│ name  │ value             │ note │"
+    "├───────┼───────────────────┼──────┤"
+    "│ table │ char   | number   │ end  │"
+    "│       │--------+----------│      │"
+    "│       │ a      | 1        │      │"
+    "│       │ b      | 2        │      │"
+    "├───────┼───────────────────┼──────┤"
+    "│       │ a long text value │      │"
+    "└───────┴───────────────────┴──────┘"
+);
+
+test_table!(
+    nested_table_collapse_with_padding,
+    outer()
+        .with(Style::modern())
+        .with(Modify::new(Rows::new(1..)).with(Padding::new(2, 2, 1, 1)))
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(inner().with(Style::modern()).clone()).collapse())),
+    "┌─────────┬─────────────────────┬───────┐"
+    "│ name    │ value               │ note  │"
+    "├─────────┼─────────┬───────────┼───────┤"
+    "│         │ char    │ number    │       │"
+    "│  table  ├─────────┼───────────┤  end  │"
+    "│         │ a       │ 1         │       │"
+    "│         ├─────────┼───────────┤       │"
+    "│         │ b       │ 2         │       │"
+    "├─────────┼─────────┴───────────┼───────┤"
+    "│         │                     │       │"
+    "│         │  a long text value  │       │"
+    "│         │                     │       │"
+    "└─────────┴─────────────────────┴───────┘"
+);
+
+test_table!(
+    nested_tables_in_nested_table,
+    Table::new([("", "")])
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 1)).with(Nested::new(
+            Table::new([("", "")])
+                .with(Style::modern())
+                .with(Modify::new(Cell(1, 0)).with(Nested::new(inner().with(Style::modern()).clone()).collapse()))
+                .clone(),
+        ).collapse())),
+    "┌──────┬──────────────────────┐"
+    "│ &str │ &str                 │"
+    "├──────┼───────────────┬──────┤"
+    "│      │ &str          │ &str │"
+    "│      ├──────┬────────┼──────┤"
+    "│      │ char │ number │      │"
+    "│      ├──────┼────────┤      │"
+    "│      │ a    │ 1      │      │"
+    "│      ├──────┼────────┤      │"
+    "│      │ b    │ 2      │      │"
+    "└──────┴──────┴────────┴──────┘"
+);
+
+test_table!(
+    nested_empty_table,
+    outer().with(Modify::new(Cell(1, 1)).with(Nested::new(Table::new(Vec::<usize>::new())).collapse())),
+    "+-------+-------------------+------+"
+    "| name  | value             | note |"
+    "+-------+-------------------+------+"
+    "| table | usize             | end  |"
+    "+-------+-------------------+------+"
+    "|       | a long text value |      |"
+    "+-------+-------------------+------+"
+);
@@ -0,0 +1,128 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::{Builder, Pivot, Unpivot},
+    group_by::Aggregate,
+    Style,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn sales() -> Builder<'static> {
+    let mut builder = Builder::default();
+    builder.set_columns(["city", "quarter", "amount"]);
+    builder.add_record(["Berlin", "Q1", "10"]);
+    builder.add_record(["Boston", "Q1", "20"]);
+    builder.add_record(["Paris", "Q2", "5"]);
+    builder.add_record(["Berlin", "Q2", "15"]);
+    builder.add_record(["Berlin", "Q2", "1"]);
+    builder
+}
+
+test_table!(
+    pivot_first_by_default,
+    sales().pivot(Pivot::new(0, 1, 2)).build().with(Style::psql()),
+    " city   | Q1 | Q2 "
+    "--------+----+----"
+    " Berlin | 10 | 15 "
+    " Boston | 20 |    "
+    " Paris  |    | 5  "
+);
+
+test_table!(
+    pivot_aggregate_and_fill,
+    sales()
+        .pivot(Pivot::new(0, 1, 2).aggregate(Aggregate::Sum).fill("0"))
+        .build()
+        .with(Style::psql()),
+    " city   | Q1 | Q2 "
+    "--------+----+----"
+    " Berlin | 10 | 16 "
+    " Boston | 20 | 0  "
+    " Paris  | 0  | 5  "
+);
+
+test_table!(
+    pivot_by_columns,
+    sales()
+        .pivot(Pivot::new(1, 0, 2).aggregate(Aggregate::Count))
+        .build()
+        .with(Style::psql()),
+    " quarter | Berlin | Boston | Paris "
+    "---------+--------+--------+-------"
+    " Q1      | 1      | 1      |       "
+    " Q2      | 2      |        | 1     "
+);
+
+test_table!(
+    pivot_with_index,
+    {
+        let mut builder = sales().pivot(Pivot::new(0, 1, 2)).index();
+        builder.set_index(0);
+        builder.build().with(Style::psql()).to_string()
+    },
+    "        | Q1 | Q2 "
+    "--------+----+----"
+    " city   |    |    "
+    " Berlin | 10 | 15 "
+    " Boston | 20 |    "
+    " Paris  |    | 5  "
+);
+
+test_table!(
+    pivot_empty,
+    Builder::default().pivot(Pivot::new(0, 1, 2)).build(),
+    "+--+"
+    "|  |"
+    "+--+"
+);
+
+test_table!(
+    unpivot_default_names,
+    {
+        let mut builder = Builder::default();
+        builder.set_columns(["city", "Q1", "Q2"]);
+        builder.add_record(["Berlin", "10", "15"]);
+        builder.add_record(["Paris", "", "5"]);
+        builder.unpivot(Unpivot::new([0])).build().with(Style::psql()).to_string()
+    },
+    " city   | variable | value "
+    "--------+----------+-------"
+    " Berlin | Q1       | 10    "
+    " Paris  | Q1       |       "
+    " Berlin | Q2       | 15    "
+    " Paris  | Q2       | 5     "
+);
+
+test_table!(
+    unpivot_without_header,
+    {
+        let builder = Builder::from_iter([["a", "b", "1"], ["c", "d", "2"]]);
+        builder
+            .unpivot(Unpivot::new([0, 1]).names("column", "number"))
+            .build()
+            .with(Style::psql())
+            .to_string()
+    },
+    " 0 | 1 | column | number "
+    "---+---+--------+--------"
+    " a | b | 2      | 1      "
+    " c | d | 2      | 2      "
+);
+
+test_table!(
+    unpivot_pivot_roundtrip,
+    sales()
+        .pivot(Pivot::new(0, 1, 2))
+        .unpivot(Unpivot::new([0]).names("quarter", "amount"))
+        .pivot(Pivot::new(0, 1, 2))
+        .build()
+        .with(Style::psql()),
+    " city   | Q1 | Q2 "
+    "--------+----+----"
+    " Berlin | 10 | 15 "
+    " Boston | 20 |    "
+    " Paris  |    | 5  "
+);
@@ -0,0 +1,136 @@
+use papergrid::records::vec_records::VecRecords;
+use tabled::{
+    builder::Builder,
+    format::Format,
+    object::{Rows, Segment},
+    rich::{RichText, Span, TextColor, TextStyle},
+    Modify, Rotate, Style, Table, Width,
+};
+
+use crate::util::static_table;
+
+mod util;
+
+fn red() -> TextStyle {
+    TextStyle::new().fg(TextColor::Red)
+}
+
+fn rich_table() -> Table<VecRecords<RichText>> {
+    let mut greeting = RichText::styled("Hello", red());
+    greeting.push(" World", TextStyle::new().bold());
+
+    let records = VecRecords::from(vec![
+        vec![RichText::from("id"), RichText::from("text")],
+        vec![RichText::from("1"), greeting],
+    ]);
+
+    Builder::custom(records).build()
+}
+
+#[test]
+fn rich_text_render() {
+    let table = rich_table().with(Style::psql()).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " id | text        "
+            "----+-------------"
+            " 1  | \u{1b}[31mHello\u{1b}[0m\u{1b}[1m World\u{1b}[0m "
+        )
+    );
+}
+
+#[test]
+fn rich_text_wrap() {
+    let table = rich_table()
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " id | text "
+            "----+------"
+            " 1  | \u{1b}[31mHel\u{1b}[0m  "
+            "    | \u{1b}[31mlo\u{1b}[0m\u{1b}[1m \u{1b}[0m  "
+            "    | \u{1b}[1mWor\u{1b}[0m  "
+            "    | \u{1b}[1mld\u{1b}[0m   "
+        )
+    );
+}
+
+#[test]
+fn rich_text_truncate() {
+    let table = rich_table()
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Width::truncate(7).suffix("..")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " id | text    "
+            "----+---------"
+            " 1  | \u{1b}[31mHello\u{1b}[0m.. "
+        )
+    );
+}
+
+#[test]
+fn rich_text_format() {
+    let table = rich_table()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Format::new(|s| format!("<{}>", s))))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " <id> | <text>        "
+            "------+---------------"
+            " <1>  | <\u{1b}[31mHello\u{1b}[0m\u{1b}[1m World\u{1b}[0m> "
+        )
+    );
+}
+
+#[test]
+fn rich_text_rotate() {
+    let table = rich_table()
+        .with(Style::psql())
+        .with(Rotate::Left)
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " text | \u{1b}[31mHello\u{1b}[0m\u{1b}[1m World\u{1b}[0m "
+            "------+-------------"
+            " id   | 1           "
+        )
+    );
+}
+
+#[test]
+fn rich_text_html_and_plain() {
+    let mut text = RichText::styled("a < b", red().bold());
+    text.push("\nc", TextStyle::new().bg(TextColor::Rgb(1, 2, 3)));
+
+    assert_eq!(text.as_str(), "a < b\nc");
+    assert_eq!(
+        text.to_html(),
+        "<span style=\"color: #cd0000; font-weight: bold\">a &lt; b</span><span style=\"background-color: #010203\"><br>c</span>"
+    );
+    assert_eq!(
+        text.to_ansi(),
+        "\u{1b}[1;31ma < b\u{1b}[0m\n\u{1b}[48;2;1;2;3mc\u{1b}[0m"
+    );
+    assert_eq!(
+        text.spans(),
+        [
+            Span::new("a < b", red().bold()),
+            Span::new("\nc", TextStyle::new().bg(TextColor::Rgb(1, 2, 3))),
+        ]
+    );
+}
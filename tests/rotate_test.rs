@@ -2,7 +2,7 @@
 
 use tabled::{
     object::{Cell, Rows},
-    Border, Highlight, Rotate,
+    Border, Highlight, Modify, Rotate, VerticalText,
 };
 
 use crate::util::{new_table, test_table};
@@ -211,3 +211,101 @@ test_table!(
     "| i32 | i32 | i32 | i32 | i32 | i32 |"
     "+-----+-----+-----+-----+-----+-----+"
 );
+
+test_table!(
+    test_rotate_cell_left,
+    new_table([("Hello", 1)]).with(Modify::new(Rows::first()).with(Rotate::Left)),
+    "+-------+---+"
+    "|   r   | 2 |"
+    "|   t   | 3 |"
+    "|   s   | i |"
+    "|   &   |   |"
+    "+-------+---+"
+    "| Hello | 1 |"
+    "+-------+---+"
+);
+
+test_table!(
+    test_rotate_cell_right,
+    new_table([("Hello", 1)]).with(Modify::new(Rows::first()).with(Rotate::Right)),
+    "+-------+---+"
+    "|   &   | i |"
+    "|   s   | 3 |"
+    "|   t   | 2 |"
+    "|   r   |   |"
+    "+-------+---+"
+    "| Hello | 1 |"
+    "+-------+---+"
+);
+
+test_table!(
+    test_rotate_cell_multiline,
+    new_table([("ab\ncde", "ab\ncde", "ab\ncde")])
+        .with(Modify::new(Cell(1, 0)).with(Rotate::Left))
+        .with(Modify::new(Cell(1, 1)).with(Rotate::Right))
+        .with(Modify::new(Cell(1, 2)).with(Rotate::Top)),
+    "+------+------+------+"
+    "| &str | &str | &str |"
+    "+------+------+------+"
+    "|   e  |  ca  | cde  |"
+    "|  bd  |  db  | ab   |"
+    "|  ac  |  e   |      |"
+    "+------+------+------+"
+);
+
+test_table!(
+    test_rotate_cell_twice_is_identity,
+    new_table([("Hello", 1)]).with(Modify::new(Rows::first()).with(Rotate::Left).with(Rotate::Right)),
+    "+-------+-----+"
+    "| &str  | i32 |"
+    "+-------+-----+"
+    "| Hello |  1  |"
+    "+-------+-----+"
+);
+
+test_table!(
+    test_rotate_cell_wide_chars,
+    new_table([("你好", 1)]).with(Modify::new(Cell(1, 0)).with(Rotate::Right)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  你  |  1  |"
+    "|  好  |     |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_vertical_text,
+    new_table([("ab\ncde", 1)]).with(Modify::new(Rows::first()).with(VerticalText)),
+    "+-----+---+"
+    "|  &  | i |"
+    "|  s  | 3 |"
+    "|  t  | 2 |"
+    "|  r  |   |"
+    "+-----+---+"
+    "| ab  | 1 |"
+    "| cde |   |"
+    "+-----+---+"
+);
+
+test_table!(
+    test_vertical_text_empty_cell,
+    new_table([("", 1)]).with(Modify::new(Cell(1, 0)).with(VerticalText)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|      |  1  |"
+    "+------+-----+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    test_rotate_cell_strips_ansi,
+    new_table([("\u{1b}[31mab\u{1b}[39m", 1)]).with(Modify::new(Cell(1, 0)).with(Rotate::Right)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  1  |"
+    "|  b   |     |"
+    "+------+-----+"
+);
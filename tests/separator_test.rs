@@ -0,0 +1,132 @@
+use tabled::{
+    locator::ByColumnName,
+    object::{Cell, Columns, Rows},
+    style::{Line, Separator},
+    Border, Modify, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table() -> Table {
+    let data = [("Apple", 3, 2), ("Pear", 4, 1), ("Total", 7, 3)];
+
+    let mut builder = Table::builder(data);
+    builder.set_columns(["fruit", "jan", "feb"]);
+    builder.build()
+}
+
+test_table!(
+    separator_adds_missing_line,
+    table()
+        .with(Style::psql())
+        .with(Separator::rows(Rows::last(), Line::full('=', '+', '|', '|'))),
+    " fruit | jan | feb "
+    "-------+-----+-----"
+    " Apple | 3   | 2   "
+    " Pear  | 4   | 1   "
+    "=======+=====+====="
+    " Total | 7   | 3   "
+);
+
+test_table!(
+    separator_bottom_line,
+    table()
+        .with(Style::modern())
+        .with(Separator::rows(Rows::single(4), Line::full('━', '┷', '┕', '┙'))),
+    "┌───────┬─────┬─────┐"
+    "│ fruit │ jan │ feb │"
+    "├───────┼─────┼─────┤"
+    "│ Apple │ 3   │ 2   │"
+    "├───────┼─────┼─────┤"
+    "│ Pear  │ 4   │ 1   │"
+    "├───────┼─────┼─────┤"
+    "│ Total │ 7   │ 3   │"
+    "┕━━━━━━━┷━━━━━┷━━━━━┙"
+);
+
+test_table!(
+    separator_columns_by_name,
+    table()
+        .with(Style::modern())
+        .with(Separator::columns(ByColumnName::new("jan"), Line::full('┃', '╂', '┰', '┸'))),
+    "┌───────┰─────┬─────┐"
+    "│ fruit ┃ jan │ feb │"
+    "├───────╂─────┼─────┤"
+    "│ Apple ┃ 3   │ 2   │"
+    "├───────╂─────┼─────┤"
+    "│ Pear  ┃ 4   │ 1   │"
+    "├───────╂─────┼─────┤"
+    "│ Total ┃ 7   │ 3   │"
+    "└───────┸─────┴─────┘"
+);
+
+test_table!(
+    separator_columns_right_line,
+    table()
+        .with(Style::blank())
+        .with(Separator::columns(Columns::single(3), Line::new(Some('|'), None, None, None))),
+    " fruit   jan   feb |"
+    " Apple   3     2   |"
+    " Pear    4     1   |"
+    " Total   7     3   |"
+);
+
+test_table!(
+    separator_latest_wins,
+    table()
+        .with(Style::ascii())
+        .with(Modify::new(Cell(1, 0)).with(Border::default().top('*')))
+        .with(Separator::rows(Rows::single(1), Line::new(Some('='), None, None, None)))
+        .with(Modify::new(Cell(1, 1)).with(Border::default().top('*'))),
+    "+-------+-----+-----+"
+    "| fruit | jan | feb |"
+    "+=======+*****+=====+"
+    "| Apple | 3   | 2   |"
+    "+-------+-----+-----+"
+    "| Pear  | 4   | 1   |"
+    "+-------+-----+-----+"
+    "| Total | 7   | 3   |"
+    "+-------+-----+-----+"
+);
+
+test_table!(
+    separator_is_reset_by_style,
+    table()
+        .with(Separator::rows(Rows::new(1..), Line::filled('~')))
+        .with(Style::psql()),
+    " fruit | jan | feb "
+    "-------+-----+-----"
+    " Apple | 3   | 2   "
+    " Pear  | 4   | 1   "
+    " Total | 7   | 3   "
+);
+
+test_table!(
+    separator_with_correct_junctions,
+    table()
+        .with(Style::modern())
+        .with(Separator::rows(Rows::last(), Line::new(Some('═'), None, None, None)))
+        .with(Style::correct_junctions()),
+    "┌───────┬─────┬─────┐"
+    "│ fruit │ jan │ feb │"
+    "├───────┼─────┼─────┤"
+    "│ Apple │ 3   │ 2   │"
+    "├───────┼─────┼─────┤"
+    "│ Pear  │ 4   │ 1   │"
+    "╞═══════╪═════╪═════╡"
+    "│ Total │ 7   │ 3   │"
+    "└───────┴─────┴─────┘"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    separator_color,
+    Table::new([[1, 2]])
+        .with(Style::psql())
+        .with(Separator::rows(Rows::last(), Line::full('=', '+', '|', '|')).color(tabled::color::Color::FG_RED)),
+    " 0 | 1 "
+    "\u{1b}[31m===+===\u{1b}[39m"
+    " 1 | 2 "
+);
@@ -0,0 +1,287 @@
+use tabled::{
+    builder::Builder,
+    object::{Cell, Columns, Object, Rows},
+    Alignment, Border, Highlight, Modify, Panel, Split, Style, Table,
+};
+
+use crate::util::{create_table, create_vector, static_table};
+
+mod util;
+
+fn to_strings<R>(tables: Vec<Table<R>>) -> Vec<String>
+where
+    R: tabled::papergrid::records::Records,
+{
+    tables.iter().map(|table| table.to_string()).collect()
+}
+
+#[test]
+fn split_height_with_sticky_header() {
+    let mut table = create_table::<4, 2>();
+    table.with(Style::ascii());
+
+    let pages = Split::height(9).sticky_rows(Rows::first()).split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                "+---+----------+----------+"
+                "| N | column 0 | column 1 |"
+                "+---+----------+----------+"
+                "| 0 |   0-0    |   0-1    |"
+                "+---+----------+----------+"
+                "| 1 |   1-0    |   1-1    |"
+                "+---+----------+----------+"
+                "| 2 |   2-0    |   2-1    |"
+                "+---+----------+----------+"
+            ),
+            static_table!(
+                "+---+----------+----------+"
+                "| N | column 0 | column 1 |"
+                "+---+----------+----------+"
+                "| 3 |   3-0    |   3-1    |"
+                "+---+----------+----------+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_height_moves_cell_settings() {
+    let mut table = create_table::<4, 2>();
+    table
+        .with(Style::ascii())
+        .with(Modify::new(Cell(4, 1)).with(Alignment::right()))
+        .with(Highlight::new(Cell(4, 2), Border::filled('*')));
+
+    let pages = Split::height(9).sticky_rows(Rows::first()).split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                "+---+----------+----------+"
+                "| N | column 0 | column 1 |"
+                "+---+----------+----------+"
+                "| 0 |   0-0    |   0-1    |"
+                "+---+----------+----------+"
+                "| 1 |   1-0    |   1-1    |"
+                "+---+----------+----------+"
+                "| 2 |   2-0    |   2-1    |"
+                "+---+----------************"
+            ),
+            static_table!(
+                "+---+----------+----------+"
+                "| N | column 0 | column 1 |"
+                "+---+----------************"
+                "| 3 |      3-0 *   3-1    *"
+                "+---+----------************"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_height_without_sticky_rows() {
+    let mut table = create_table::<2, 1>();
+    table.with(Style::ascii());
+
+    let pages = Split::height(5).split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                "+---+----------+"
+                "| N | column 0 |"
+                "+---+----------+"
+                "| 0 |   0-0    |"
+                "+---+----------+"
+            ),
+            static_table!(
+                "+---+-----+"
+                "| 1 | 1-0 |"
+                "+---+-----+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_height_with_panel_and_footer() {
+    let data = create_vector::<3, 1>();
+    let mut table = Table::new(&data);
+    table
+        .with(Style::modern())
+        .with(Panel::header("Header"))
+        .with(Panel::footer("Footer"));
+
+    let pages = Split::height(9)
+        .sticky_rows(Rows::first().and(Rows::single(1)).and(Rows::last()))
+        .split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                "┌───┬──────────┐"
+                "│ Header       │"
+                "├───┼──────────┤"
+                "│ N │ column 0 │"
+                "├───┼──────────┤"
+                "│ 0 │ 0-0      │"
+                "├───┼──────────┤"
+                "│ Footer       │"
+                "└───┴──────────┘"
+            ),
+            static_table!(
+                "┌───┬──────────┐"
+                "│ Header       │"
+                "├───┼──────────┤"
+                "│ N │ column 0 │"
+                "├───┼──────────┤"
+                "│ 1 │ 1-0      │"
+                "├───┼──────────┤"
+                "│ Footer       │"
+                "└───┴──────────┘"
+            ),
+            static_table!(
+                "┌───┬──────────┐"
+                "│ Header       │"
+                "├───┼──────────┤"
+                "│ N │ column 0 │"
+                "├───┼──────────┤"
+                "│ 2 │ 2-0      │"
+                "├───┼──────────┤"
+                "│ Footer       │"
+                "└───┴──────────┘"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_width_with_sticky_index() {
+    let mut builder = Builder::default();
+    builder.set_columns(["name", "a", "b", "c"]);
+    builder.add_record(["x", "1", "2", "3"]);
+    builder.add_record(["y", "4", "5", "6"]);
+
+    let mut table = builder.index().build();
+    table.with(Style::psql());
+
+    let pages = Split::width(14)
+        .sticky_columns(Columns::first())
+        .split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                "   | name | a "
+                "---+------+---"
+                " 0 | x    | 1 "
+                " 1 | y    | 4 "
+            ),
+            static_table!(
+                "   | b | c "
+                "---+---+---"
+                " 0 | 2 | 3 "
+                " 1 | 5 | 6 "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_width_cuts_panel() {
+    let mut table = create_table::<1, 3>();
+    table.with(Style::modern()).with(Panel::header("Header"));
+
+    let pages = Split::width(20)
+        .sticky_columns(Columns::first())
+        .split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                "┌───┬──────────┐"
+                "│    Header    │"
+                "├───┼──────────┤"
+                "│ N │ column 0 │"
+                "├───┼──────────┤"
+                "│ 0 │   0-0    │"
+                "└───┴──────────┘"
+            ),
+            static_table!(
+                "┌───┬──────────┐"
+                "│    Header    │"
+                "├───┼──────────┤"
+                "│ N │ column 1 │"
+                "├───┼──────────┤"
+                "│ 0 │   0-1    │"
+                "└───┴──────────┘"
+            ),
+            static_table!(
+                "┌───┬──────────┐"
+                "│    Header    │"
+                "├───┼──────────┤"
+                "│ N │ column 2 │"
+                "├───┼──────────┤"
+                "│ 0 │   0-2    │"
+                "└───┴──────────┘"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_width_moves_span_text() {
+    let mut table = create_table::<1, 3>();
+    table.with(Style::psql()).with(Panel::header("Header"));
+
+    let pages = Split::width(12).split(&table);
+
+    assert_eq!(
+        to_strings(pages),
+        [
+            static_table!(
+                " Header "
+                "--------"
+                "   N    "
+                "   0    "
+            ),
+            static_table!(
+                "  Header  "
+                "----------"
+                " column 0 "
+                "   0-0    "
+            ),
+            static_table!(
+                "  Header  "
+                "----------"
+                " column 1 "
+                "   0-1    "
+            ),
+            static_table!(
+                "  Header  "
+                "----------"
+                " column 2 "
+                "   0-2    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_fitting_table() {
+    let table = create_table::<3, 3>();
+
+    let pages = Split::height(100).sticky_rows(Rows::first()).split(&table);
+    assert_eq!(to_strings(pages), [table.to_string()]);
+
+    let pages = Split::width(1).split(&create_table::<0, 0>());
+    assert_eq!(pages.len(), 1);
+}
@@ -7,7 +7,7 @@ use tabled::{
     format::Format,
     object::{Cell, Columns, Rows, Segment},
     style::{BorderChar, HorizontalLine, Line, Offset, RawStyle, VerticalLine},
-    Border, BorderText, Highlight, Modify, Padding, Span, Style, Table,
+    Alignment, Border, BorderText, Highlight, Modify, Padding, Span, Style, Table,
 };
 
 mod util;
@@ -433,6 +433,156 @@ test_table!(
     "+---+----------+----------+"
 );
 
+test_table!(
+    border_text_column_alignment,
+    create_table::<2, 2>()
+        .with(BorderText::first("L").column(0).alignment(Alignment::left()))
+        .with(BorderText::first("C").column(1).alignment(Alignment::center()))
+        .with(BorderText::first("R").column(2).alignment(Alignment::right())),
+    "+L--+----C-----+---------R+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    border_text_column_offset,
+    create_table::<2, 2>()
+        .with(BorderText::new(1, "ab").column(1).offset(Offset::Begin(1)))
+        .with(BorderText::new(2, "ab").column(1).offset(Offset::End(3))),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+-ab-------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+-------ab-+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    border_text_column_span,
+    create_table::<2, 2>()
+        .with(Modify::new(Cell(0, 1)).with(Span::column(2)))
+        .with(BorderText::first("Title").column(1).alignment(Alignment::center())),
+    "+---+---Title---+"
+    "| N | column 0  |"
+    "+---+-----+-----+"
+    "| 0 | 0-0 | 0-1 |"
+    "+---+-----+-----+"
+    "| 1 | 1-0 | 1-1 |"
+    "+---+-----+-----+"
+);
+
+test_table!(
+    border_text_multiple_labels,
+    create_table::<2, 2>()
+        .with(BorderText::first("Left"))
+        .with(BorderText::first("Right").offset(Offset::End(6))),
+    "Left+----------+-----Right+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    border_text_multiple_labels_overlap,
+    create_table::<2, 2>()
+        .with(BorderText::last("ABCDEFGH").offset(Offset::Begin(2)))
+        .with(BorderText::last("xy").offset(Offset::Begin(4))),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+-ABxyEFGH-----+----------+"
+);
+
+test_table!(
+    border_text_column_invalid_index,
+    create_table::<2, 2>().with(BorderText::first("Title").column(10)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    border_text_vertical,
+    create_table::<2, 2>()
+        .with(BorderText::left("ABCDEFG"))
+        .with(BorderText::right("xyz").offset(Offset::End(3))),
+    "A---+----------+----------+"
+    "B N | column 0 | column 1 |"
+    "C---+----------+----------+"
+    "D 0 |   0-0    |   0-1    |"
+    "E---+----------+----------x"
+    "F 1 |   1-0    |   1-1    y"
+    "G---+----------+----------z"
+);
+
+test_table!(
+    border_text_vertical_row_alignment,
+    create_table::<2, 2>()
+        .with(Modify::new(Rows::single(1)).with(Format::new(|s| format!("{}\n\n", s))))
+        .with(BorderText::vertical(1, "ab").row(1).alignment(Alignment::bottom()))
+        .with(BorderText::vertical(2, "c").row(1).alignment(Alignment::center_vertical())),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "|   a          c          |"
+    "|   b          |          |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    border_text_vertical_ignores_horizontal_alignment,
+    create_table::<2, 2>().with(BorderText::left("AB").row(2).alignment(Alignment::right())),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "A 1 |   1-0    |   1-1    |"
+    "B---+----------+----------+"
+);
+
+test_table!(
+    border_text_vertical_without_line,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(BorderText::left("AB")),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    border_text_color,
+    create_table::<1, 1>()
+        .with(BorderText::first("T").column(1).color(tabled::color::Color::FG_RED))
+        .with(BorderText::right("x").row(1).color(tabled::color::Color::FG_BLUE)),
+    "+---+\u{1b}[31mT\u{1b}[39m---------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    \u{1b}[34mx\u{1b}[39m"
+    "+---+----------+"
+);
+
 test_table!(
     empty_style,
     create_table::<3, 3>()
@@ -2605,3 +2755,65 @@ test_table!(
     "|     ! |xxxx"
     "+-------+xxxx"
 );
+
+test_table!(
+    correct_junctions_double_header_line,
+    create_table::<2, 2>()
+        .with(Style::modern().horizontals([HorizontalLine::new(1, Style::extended().get_horizontal())]))
+        .with(Style::correct_junctions()),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "╞═══╪══════════╪══════════╡"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    correct_junctions_heavy_lines,
+    create_table::<2, 2>()
+        .with(
+            Style::modern()
+                .horizontals([HorizontalLine::new(1, Line::full('━', '┼', '├', '┤'))])
+                .verticals([VerticalLine::new(1, Line::full('┃', '┼', '┬', '┴'))])
+        )
+        .with(Style::correct_junctions()),
+    "┌───┰──────────┬──────────┐"
+    "│ N ┃ column 0 │ column 1 │"
+    "┝━━━╋━━━━━━━━━━┿━━━━━━━━━━┥"
+    "│ 0 ┃   0-0    │   0-1    │"
+    "├───╂──────────┼──────────┤"
+    "│ 1 ┃   1-0    │   1-1    │"
+    "└───┸──────────┴──────────┘"
+);
+
+test_table!(
+    correct_junctions_spans,
+    create_table::<3, 3>()
+        .with(Style::modern().horizontals([HorizontalLine::new(1, Style::extended().get_horizontal())]))
+        .with(Modify::new(Cell(0, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 0)).with(Span::row(2)))
+        .with(Style::correct_junctions()),
+    "┌───┬───────────┬──────────┐"
+    "│ N │ column 0  │ column 2 │"
+    "╞═══╪═════╤═════╪══════════╡"
+    "│ 0 │ 0-0 │ 0-1 │   0-2    │"
+    "│   ├─────┼─────┼──────────┤"
+    "│   │ 1-0 │ 1-1 │   1-2    │"
+    "├───┼─────┼─────┼──────────┤"
+    "│ 2 │ 2-0 │ 2-1 │   2-2    │"
+    "└───┴─────┴─────┴──────────┘"
+);
+
+test_table!(
+    correct_junctions_ignores_ascii,
+    create_table::<1, 1>()
+        .with(Style::ascii().horizontals([HorizontalLine::new(1, Style::extended().get_horizontal())]))
+        .with(Style::correct_junctions()),
+    "+---+----------+"
+    "| N | column 0 |"
+    "╠═══╬══════════╣"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
@@ -0,0 +1,191 @@
+use tabled::{
+    style::{RawStyle, Themes},
+    Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table() -> Table {
+    Table::new([("Hello", 1)])
+}
+
+#[test]
+fn themes_have_presets() {
+    let themes = Themes::new();
+
+    assert_eq!(
+        themes.names().collect::<Vec<_>>(),
+        [
+            "ascii",
+            "ascii_rounded",
+            "blank",
+            "dots",
+            "empty",
+            "extended",
+            "markdown",
+            "modern",
+            "psql",
+            "re_structured_text",
+            "rounded",
+            "sharp",
+        ]
+    );
+
+    for (name, style) in themes.iter() {
+        let preset = match name {
+            "ascii" => table().with(Style::ascii()).to_string(),
+            "ascii_rounded" => table().with(Style::ascii_rounded()).to_string(),
+            "blank" => table().with(Style::blank()).to_string(),
+            "dots" => table().with(Style::dots()).to_string(),
+            "empty" => table().with(Style::empty()).to_string(),
+            "extended" => table().with(Style::extended()).to_string(),
+            "markdown" => table().with(Style::markdown()).to_string(),
+            "modern" => table().with(Style::modern()).to_string(),
+            "psql" => table().with(Style::psql()).to_string(),
+            "re_structured_text" => table().with(Style::re_structured_text()).to_string(),
+            "rounded" => table().with(Style::rounded()).to_string(),
+            "sharp" => table().with(Style::sharp()).to_string(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(table().with(style).to_string(), preset, "{}", name);
+    }
+}
+
+#[test]
+fn themes_insert_and_remove() {
+    let mut themes = Themes::empty();
+    assert_eq!(themes.names().count(), 0);
+
+    let mut style = RawStyle::from(Style::ascii());
+    style.set_horizontal(Some('~'));
+
+    assert!(themes.insert("wavy", style).is_none());
+    assert!(themes
+        .insert("wavy", RawStyle::from(Style::psql()))
+        .is_some());
+    assert!(themes.get("wavy").is_some());
+    assert!(themes.remove("wavy").is_some());
+    assert!(themes.get("wavy").is_none());
+
+    let themes = vec![("a", RawStyle::from(Style::ascii()))]
+        .into_iter()
+        .collect::<Themes>();
+    assert_eq!(themes.names().collect::<Vec<_>>(), ["a"]);
+}
+
+test_table!(
+    themes_get_style,
+    table().with(Themes::default().get("rounded").unwrap()),
+    "╭───────┬─────╮"
+    "│ &str  │ i32 │"
+    "├───────┼─────┤"
+    "│ Hello │ 1   │"
+    "╰───────┴─────╯"
+);
+
+#[cfg(feature = "serde")]
+#[test]
+fn raw_style_serialize() {
+    let style = RawStyle::from(Style::psql());
+
+    assert_eq!(
+        serde_json::to_string(&style).unwrap(),
+        r#"{"vertical":"|","horizontals":[{"index":1,"main":"-","intersection":"+"}]}"#
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn raw_style_deserialize() {
+    let style: RawStyle = serde_json::from_str(
+        r#"{
+            "top": "=", "bottom": "=", "left": "[", "right": "]",
+            "top_left": "=", "top_right": "=", "bottom_left": "=", "bottom_right": "=",
+            "top_intersection": "=", "bottom_intersection": "=",
+            "vertical": "|",
+            "horizontals": [{ "index": 1, "main": "-", "left": "[", "right": "]", "intersection": "+" }],
+            "verticals": [{ "index": 1, "main": ":" }]
+        }"#,
+    )
+    .unwrap();
+
+    let mut table = Table::new([("Hello", 1), ("World", 2)]);
+    table.with(style);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "===============\n",
+            "[ &str  : i32 ]\n",
+            "[-------+-----]\n",
+            "[ Hello : 1   ]\n",
+            "[ World : 2   ]\n",
+            "===============",
+        )
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn raw_style_deserialize_rejects_colors() {
+    let json = r#"{ "vertical": "|", "colors": { "vertical": { "prefix": "\u001b[31m", "suffix": "\u001b[39m" } } }"#;
+
+    let err = serde_json::from_str::<RawStyle>(json).unwrap_err();
+    assert!(err.to_string().contains("RawStyleColored"));
+
+    let err = serde_json::from_str::<Themes>(&format!(r#"{{ "red": {} }}"#, json)).unwrap_err();
+    assert!(err.to_string().contains("RawStyleColored"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn themes_serde_roundtrip() {
+    let themes = Themes::new();
+
+    let json = serde_json::to_string(&themes).unwrap();
+    let restored: Themes = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        restored.names().collect::<Vec<_>>(),
+        themes.names().collect::<Vec<_>>()
+    );
+
+    for (name, style) in themes.iter() {
+        assert_eq!(
+            table().with(restored.get(name).unwrap()).to_string(),
+            table().with(style).to_string(),
+            "{}",
+            name
+        );
+    }
+
+    let mut themes = Themes::new();
+    let custom: Themes = serde_json::from_str(r#"{ "minimal": { "vertical": "|" } }"#).unwrap();
+    themes.extend(custom);
+
+    assert_eq!(
+        table().with(themes.get("minimal").unwrap()).to_string(),
+        " &str  | i32 \n Hello | 1   "
+    );
+}
+
+#[cfg(all(feature = "serde", feature = "color"))]
+#[test]
+fn raw_style_colored_serde_roundtrip() {
+    use tabled::style::{RawStyleColored, Symbol};
+
+    let mut style = RawStyleColored::from(RawStyle::from(Style::ascii()));
+    style.set_vertical(Some(Symbol::ansi("\u{1b}[31m|\u{1b}[39m").unwrap()));
+
+    let json = serde_json::to_string(&style).unwrap();
+    assert!(json.contains(r#""colors":{"vertical":{"prefix":"\u001b[31m","suffix":"\u001b[39m"}}"#));
+
+    let restored: RawStyleColored = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        table().with(restored).to_string(),
+        table().with(style).to_string()
+    );
+}
@@ -5,11 +5,12 @@ use tabled::ModifyObject;
 
 use tabled::{
     formatting::TrimStrategy,
+    locator::ByColumnName,
     object::{Cell, Columns, Object, Rows, Segment},
     papergrid::util::string_width_multiline,
     peaker::{PriorityMax, PriorityMin},
-    width::{Justify, MinWidth, SuffixLimit, Width},
-    Alignment, Margin, Modify, Padding, Panel, Span, Style, Table,
+    width::{FitStep, Flex, Justify, MinWidth, SuffixLimit, Width},
+    Alignment, Disable, Margin, Modify, Padding, Panel, Span, Style, Table,
 };
 
 use crate::util::{create_table, init_table, is_lines_equal, new_table, static_table};
@@ -2319,6 +2320,213 @@ fn wrap_keeping_words_0() {
     assert_eq!(table, expected,);
 }
 
+#[test]
+fn distribute_increase_by_weight() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::distribute(50).column(1, Flex::new().weight(3)))
+        .to_string();
+
+    assert_eq!(papergrid::util::string_width_multiline(&table), 50);
+    assert_eq!(
+        table,
+        static_table!(
+            "|  N  |    column 0    |  column 1  |  column 2  |"
+            "|-----|----------------|------------|------------|"
+            "|  0  |      0-0       |    0-1     |    0-2     |"
+            "|  1  |      1-0       |    1-1     |    1-2     |"
+            "|  2  |      2-0       |    2-1     |    2-2     |"
+        )
+    );
+}
+
+#[test]
+fn distribute_decrease_by_weight() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(
+            Width::distribute(30)
+                .column(0, Flex::fixed())
+                .column(2, Flex::new().weight(2)),
+        )
+        .to_string();
+
+    assert_eq!(papergrid::util::string_width_multiline(&table), 30);
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column | colu | column |"
+            "|   |  0     | mn 1 |  2     |"
+            "|---|--------|------|--------|"
+            "| 0 |  0-0   | 0-1  |  0-2   |"
+            "| 1 |  1-0   | 1-1  |  1-2   |"
+            "| 2 |  2-0   | 2-1  |  2-2   |"
+        )
+    );
+}
+
+#[test]
+fn distribute_respects_min_and_max() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(
+            Width::distribute(60)
+                .column(0, Flex::new().max(5))
+                .column(1, Flex::new().min(20))
+                .column(3, Flex::new().no_grow()),
+        )
+        .to_string();
+
+    assert_eq!(papergrid::util::string_width_multiline(&table), 60);
+    assert_eq!(
+        table,
+        static_table!(
+            "|  N  |        column 0         |   column 1    | column 2 |"
+            "|-----|-------------------------|---------------|----------|"
+            "|  0  |           0-0           |      0-1      |   0-2    |"
+            "|  1  |           1-0           |      1-1      |   1-2    |"
+            "|  2  |           2-0           |      2-1      |   2-2    |"
+        )
+    );
+}
+
+#[test]
+fn distribute_never_shrink_columns() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(
+            Width::distribute(10)
+                .column(1, Flex::new().no_shrink())
+                .truncate(),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  | column 0 |  |  |"
+            "|--|----------|--|--|"
+            "|  |   0-0    |  |  |"
+            "|  |   1-0    |  |  |"
+            "|  |   2-0    |  |  |"
+        )
+    );
+}
+
+#[test]
+fn distribute_keeps_width_when_it_is_reached() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::distribute(38))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column 0 | column 1 | column 2 |"
+            "|---|----------|----------|----------|"
+            "| 0 |   0-0    |   0-1    |   0-2    |"
+            "| 1 |   1-0    |   1-1    |   1-2    |"
+            "| 2 |   2-0    |   2-1    |   2-2    |"
+        )
+    );
+}
+
+#[test]
+fn fit_does_nothing_if_table_fits() {
+    let mut fit = Width::fit(38);
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(&mut fit)
+        .to_string();
+
+    assert!(fit.applied().is_empty());
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column 0 | column 1 | column 2 |"
+            "|---|----------|----------|----------|"
+            "| 0 |   0-0    |   0-1    |   0-2    |"
+            "| 1 |   1-0    |   1-1    |   1-2    |"
+            "| 2 |   2-0    |   2-1    |   2-2    |"
+        )
+    );
+}
+
+#[test]
+fn fit_removes_padding_first() {
+    let mut fit = Width::fit(32);
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(&mut fit)
+        .to_string();
+
+    assert_eq!(fit.applied(), [FitStep::Padding]);
+    assert_eq!(
+        table,
+        static_table!(
+            "|N|column 0|column 1|column 2|"
+            "|-|--------|--------|--------|"
+            "|0|  0-0   |  0-1   |  0-2   |"
+            "|1|  1-0   |  1-1   |  1-2   |"
+            "|2|  2-0   |  2-1   |  2-2   |"
+        )
+    );
+}
+
+#[test]
+fn fit_wraps_and_truncates() {
+    let mut fit = Width::fit(18).steps([FitStep::Padding, FitStep::Wrap(6), FitStep::Truncate(4)]);
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(&mut fit)
+        .to_string();
+
+    assert_eq!(
+        fit.applied(),
+        [FitStep::Padding, FitStep::Wrap(6), FitStep::Truncate(4)]
+    );
+    assert_eq!(papergrid::util::string_width_multiline(&table), 18);
+    assert_eq!(
+        table,
+        static_table!(
+            "|N|colu|colu|colu|"
+            "|-|----|----|----|"
+            "|0|0-0 |0-1 |0-2 |"
+            "|1|1-0 |1-1 |1-2 |"
+            "|2|2-0 |2-1 |2-2 |"
+        )
+    );
+}
+
+#[test]
+fn fit_disables_columns() {
+    let mut fit = Width::fit(20)
+        .steps([FitStep::Wrap(8), FitStep::Disable])
+        .disable([
+            Disable::column(ByColumnName::new("column 2")),
+            Disable::column(ByColumnName::new("column 1")),
+            Disable::column(ByColumnName::new("column 0")),
+        ]);
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(&mut fit)
+        .to_string();
+
+    assert_eq!(fit.applied(), [FitStep::Wrap(8), FitStep::Disable]);
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column |"
+            "|   |  0     |"
+            "|---|--------|"
+            "| 0 |  0-0   |"
+            "| 1 |  1-0   |"
+            "| 2 |  2-0   |"
+        )
+    );
+}
+
 #[cfg(feature = "derive")]
 mod derived {
     use super::*;